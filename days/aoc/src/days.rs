@@ -0,0 +1,54 @@
+use anyhow::anyhow;
+use common::{DayInputs, GridView, PartReport, Report, Solution};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// A registered day, type erased so every day can be driven the same way.
+pub struct Day {
+    pub day: u8,
+    pub inputs: fn() -> DayInputs,
+    pub execute: fn(&str) -> anyhow::Result<Report>,
+    pub execute_part: fn(&str, u8) -> anyhow::Result<(Duration, PartReport)>,
+    pub grid_view: fn(&str) -> anyhow::Result<Option<GridView>>,
+    pub plausibility: fn(&str) -> anyhow::Result<f64>,
+    /// Synthetic input from a seed and a size
+    pub generate: fn(u64, usize) -> String,
+}
+
+impl Day {
+    const fn new<S: Solution>(
+        inputs: fn() -> DayInputs,
+        generate: fn(u64, usize) -> String,
+    ) -> Self {
+        Day {
+            day: S::DAY,
+            inputs,
+            execute: common::execute::<S>,
+            execute_part: common::execute_part::<S>,
+            grid_view: common::grid_view::<S>,
+            plausibility: common::plausibility::<S>,
+            generate,
+        }
+    }
+
+    /// Crate directory of the day, the days live next to this crate.
+    pub fn dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).with_file_name(format!("day{}", self.day))
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(day1::inputs, day1::generate::generate),
+    Day::new::<day2::Day2>(day2::inputs, day2::generate::generate),
+    Day::new::<day3::Day3>(day3::inputs, day3::generate::generate),
+    Day::new::<day4::Day4>(day4::inputs, day4::generate::generate),
+    Day::new::<day5::Day5>(day5::inputs, day5::generate::generate),
+];
+
+pub fn find(day: u8) -> anyhow::Result<&'static Day> {
+    DAYS.iter()
+        .find(|registered| registered.day == day)
+        .ok_or(anyhow!("Day {day} isn't registered"))
+}
@@ -0,0 +1,29 @@
+use crate::days;
+use clap::Args;
+use std::{fs, path::PathBuf};
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(short, long)]
+    day: u8,
+    /// Same seed and size give the same input
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+    /// Number of lines, reports, instructions or updates, the side length for grids
+    #[arg(short = 'n', long, default_value_t = 1000)]
+    size: usize,
+    /// Write the input to this file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+pub fn generate(args: GenerateArgs) -> anyhow::Result<()> {
+    let day = days::find(args.day)?;
+    let input = (day.generate)(args.seed, args.size);
+    match args.output {
+        Some(path) => fs::write(path, input)?,
+        None => print!("{input}"),
+    }
+    Ok(())
+}
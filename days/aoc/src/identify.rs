@@ -0,0 +1,110 @@
+use crate::{days::DAYS, table::print_table};
+use anyhow::bail;
+use clap::Args;
+use common::{get_input, InputType};
+use std::path::PathBuf;
+
+/// Below this the best guess isn't reported.
+const MIN_CONFIDENCE: f64 = 0.05;
+
+#[derive(Args)]
+pub struct IdentifyArgs {
+    /// Input file of unknown day
+    file: PathBuf,
+}
+
+pub struct Candidate {
+    pub day: u8,
+    /// Plausibility of the input for this day, or why it didn't parse
+    pub score: anyhow::Result<f64>,
+}
+
+/// Runs every registered day's parser on `input`, most plausible day first.
+pub fn rank(input: &str) -> Vec<Candidate> {
+    let mut candidates = DAYS
+        .iter()
+        .map(|day| Candidate {
+            day: day.day,
+            score: (day.plausibility)(input),
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_by(|left, right| score(right).total_cmp(&score(left)));
+    candidates
+}
+
+fn score(candidate: &Candidate) -> f64 {
+    *candidate.score.as_ref().unwrap_or(&0.0)
+}
+
+/// Score weighted by its share of the `total` of all scores, high when the input is plausible
+/// for this day and not for the others.
+fn confidence(candidate: &Candidate, total: f64) -> f64 {
+    score(candidate) * score(candidate) / total.max(f64::MIN_POSITIVE)
+}
+
+pub fn identify(args: IdentifyArgs) -> anyhow::Result<()> {
+    let input = get_input(InputType::Input(args.file))?;
+    let candidates = rank(&input);
+    let total = candidates.iter().map(score).sum::<f64>();
+
+    let rows = candidates
+        .iter()
+        .map(|candidate| {
+            let parser = match &candidate.score {
+                Ok(_) => String::from("ok"),
+                Err(err) => summary(&format!("{err:#}")),
+            };
+            vec![
+                candidate.day.to_string(),
+                parser,
+                format!("{:.2}", score(candidate)),
+                format!("{:.0}%", 100.0 * confidence(candidate, total)),
+            ]
+        })
+        .collect::<Vec<_>>();
+    print_table(&["Day", "Parser", "Score", "Confidence"], &rows);
+
+    let best = &candidates[0];
+    if confidence(best, total) < MIN_CONFIDENCE {
+        bail!("No registered day fits this input");
+    }
+    println!(
+        "\nMost plausible: day {} ({:.0}% confidence)",
+        best.day,
+        100.0 * confidence(best, total)
+    );
+    Ok(())
+}
+
+/// First line of a parser error, which can quote the whole remaining input.
+fn summary(error: &str) -> String {
+    let line = error.lines().next().unwrap_or_default();
+    match line.char_indices().nth(60) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        days,
+        identify::{confidence, rank, score, MIN_CONFIDENCE},
+    };
+    use std::fs;
+
+    #[test]
+    fn test_examples() -> anyhow::Result<()> {
+        for day in 1..=5 {
+            let example = fs::read_to_string(days::find(day)?.dir().join("test.txt"))?;
+            let candidates = rank(&example);
+            let total = candidates.iter().map(score).sum::<f64>();
+            assert_eq!(candidates[0].day, day);
+            assert!(
+                confidence(&candidates[0], total) >= MIN_CONFIDENCE,
+                "day {day}"
+            );
+        }
+        Ok(())
+    }
+}
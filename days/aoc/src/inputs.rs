@@ -0,0 +1,84 @@
+use anyhow::bail;
+use clap::Subcommand;
+use common::crypto::{self, InputKey, KEY_FILE_NAME};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Subcommand)]
+pub enum InputsCommand {
+    /// Generate a new key and write it to .aoc-key
+    Keygen {
+        /// Overwrite an existing key file
+        #[arg(long)]
+        force: bool,
+    },
+    /// Encrypt inputs into <input>.enc, defaults to every days/*/input.txt
+    Encrypt { paths: Vec<PathBuf> },
+    /// Decrypt <input>.enc files back into inputs, defaults to every days/*/input.txt.enc
+    Decrypt { paths: Vec<PathBuf> },
+}
+
+pub fn run(command: InputsCommand) -> anyhow::Result<()> {
+    match command {
+        InputsCommand::Keygen { force } => keygen(force),
+        InputsCommand::Encrypt { paths } => encrypt(paths),
+        InputsCommand::Decrypt { paths } => decrypt(paths),
+    }
+}
+
+fn keygen(force: bool) -> anyhow::Result<()> {
+    let path = Path::new(KEY_FILE_NAME);
+    if path.exists() && !force {
+        bail!("{KEY_FILE_NAME} already exists, use --force to overwrite it");
+    }
+    fs::write(path, InputKey::generate().to_hex())?;
+    println!("Wrote {KEY_FILE_NAME}, keep it out of git");
+    Ok(())
+}
+
+fn encrypt(paths: Vec<PathBuf>) -> anyhow::Result<()> {
+    let key = InputKey::load()?;
+    let paths = match paths.is_empty() {
+        true => day_files("input.txt")?,
+        false => paths,
+    };
+    for path in paths {
+        let encrypted = crypto::encrypted_path(&path);
+        fs::write(&encrypted, key.encrypt(&fs::read(&path)?)?)?;
+        println!("{} -> {}", path.display(), encrypted.display());
+    }
+    Ok(())
+}
+
+fn decrypt(paths: Vec<PathBuf>) -> anyhow::Result<()> {
+    let key = InputKey::load()?;
+    let paths = match paths.is_empty() {
+        true => day_files("input.txt.enc")?,
+        false => paths,
+    };
+    for path in paths {
+        let Some(decrypted) = crypto::decrypted_path(&path) else {
+            bail!(
+                "{} doesn't end in .{}",
+                path.display(),
+                crypto::ENCRYPTED_EXTENSION
+            );
+        };
+        fs::write(&decrypted, key.decrypt(&fs::read(&path)?)?)?;
+        println!("{} -> {}", path.display(), decrypted.display());
+    }
+    Ok(())
+}
+
+/// Finds `file_name` in every `days/*` directory below the current one.
+fn day_files(file_name: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let mut paths = fs::read_dir("days")?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join(file_name))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+    Ok(paths)
+}
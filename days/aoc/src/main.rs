@@ -0,0 +1,56 @@
+mod days;
+mod generate;
+mod identify;
+mod inputs;
+mod run;
+mod serve;
+mod snapshot;
+mod table;
+mod tui;
+mod watch;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Manage encrypted puzzle inputs
+    Inputs {
+        #[command(subcommand)]
+        command: inputs::InputsCommand,
+    },
+    /// Run days and check their answers against the .answers files
+    Run(run::RunArgs),
+    /// Rebuild and rerun a day whenever its sources or inputs change
+    Watch(watch::WatchArgs),
+    /// Browse days, run them and look at their grids in the terminal
+    Tui,
+    /// Serve the solvers over HTTP on localhost
+    Serve(serve::ServeArgs),
+    /// Guess which day an input file belongs to by running every day's parser on it
+    Identify(identify::IdentifyArgs),
+    /// Generate a synthetic input for a day
+    Gen(generate::GenerateArgs),
+    /// Check the parsed examples against their snapshots, or accept new ones
+    Snapshot(snapshot::SnapshotArgs),
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Inputs { command } => inputs::run(command),
+        Command::Run(args) => run::run(args),
+        Command::Watch(args) => watch::watch(args),
+        Command::Tui => tui::tui(),
+        Command::Serve(args) => serve::serve(args),
+        Command::Identify(args) => identify::identify(args),
+        Command::Gen(args) => generate::generate(args),
+        Command::Snapshot(args) => snapshot::snapshot(args),
+    }
+}
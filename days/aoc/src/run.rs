@@ -0,0 +1,386 @@
+use crate::{
+    days::{self, Day, DAYS},
+    table::print_table,
+};
+use anyhow::bail;
+use clap::{Args, ValueEnum};
+use common::{answers::Answers, get_input, InputType, Report};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeSet,
+    fs,
+    num::NonZero,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Day to run, can be repeated
+    #[arg(short, long, required_unless_present = "all")]
+    day: Vec<u8>,
+    /// Run every registered day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Use the example inputs instead of the real ones
+    #[arg(long)]
+    example: bool,
+    /// Solve every input in this directory with a single day, `<name>.answers` holds the
+    /// expected answers of `<name>.txt`
+    #[arg(long, conflicts_with_all = ["all", "example"])]
+    inputs_dir: Option<PathBuf>,
+    /// Run the days, or the inputs of --inputs-dir, in parallel across all cores
+    #[arg(short, long)]
+    parallel: bool,
+    /// Write a Chrome trace of the run to this file
+    #[arg(long)]
+    trace: Option<PathBuf>,
+    /// Output format of the results
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+pub struct DayResult {
+    pub day: u8,
+    /// Which input was solved, `example`, `input` or the file name in batch mode
+    pub input: String,
+    pub answers: Answers,
+    pub report: anyhow::Result<Report>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    Unverified,
+}
+
+impl Status {
+    /// An error always fails, an answer without an expected one is unverified.
+    pub fn check(expected: Option<&String>, answer: &anyhow::Result<String>) -> Status {
+        match (expected, answer) {
+            (_, Err(_)) => Status::Fail,
+            (None, Ok(_)) => Status::Unverified,
+            (Some(expected), Ok(answer)) if expected == answer => Status::Pass,
+            (Some(_), Ok(_)) => Status::Fail,
+        }
+    }
+}
+
+impl DayResult {
+    pub fn statuses(&self) -> [Status; 2] {
+        match &self.report {
+            Ok(report) => [
+                Status::check(self.answers.part_1.as_ref(), &report.part_1.answer),
+                Status::check(self.answers.part_2.as_ref(), &report.part_2.answer),
+            ],
+            Err(_) => [Status::Fail; 2],
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.statuses().contains(&Status::Fail)
+    }
+}
+
+pub fn run(args: RunArgs) -> anyhow::Result<()> {
+    let _guard = args
+        .trace
+        .map(common::trace::init_chrome_trace)
+        .transpose()?;
+
+    let results = match &args.inputs_dir {
+        Some(dir) => {
+            let [day] = args.day.as_slice() else {
+                bail!("--inputs-dir needs exactly one --day");
+            };
+            let day = days::find(*day)?;
+            let inputs = inputs_in_dir(dir)?;
+            map_parallel(&inputs, args.parallel, |path| {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                run_input(day, name.into_owned(), InputType::Input(path.clone()))
+            })
+        }
+        None => {
+            let days = match args.all {
+                true => DAYS.iter().collect::<Vec<_>>(),
+                false => args
+                    .day
+                    .iter()
+                    .map(|&day| days::find(day))
+                    .collect::<anyhow::Result<Vec<_>>>()?,
+            };
+            map_parallel(&days, args.parallel, |day| run_day(day, args.example))
+        }
+    };
+
+    match args.format {
+        Format::Table => print_results(&results),
+        Format::Json => {
+            let records = results.iter().map(DayRecord::from).collect::<Vec<_>>();
+            println!("{}", serde_json::to_string(&records)?);
+        }
+    }
+    let failed = results
+        .iter()
+        .filter(|result| result.failed())
+        .map(|result| format!("day {} {}", result.day, result.input))
+        .collect::<Vec<_>>();
+    if !failed.is_empty() {
+        bail!("Failed verification: {}", failed.join(", "));
+    }
+    Ok(())
+}
+
+pub fn run_day(day: &Day, example: bool) -> DayResult {
+    let inputs = (day.inputs)();
+    match example {
+        true => run_input(day, String::from("example"), inputs.example),
+        false => run_input(day, String::from("input"), inputs.input),
+    }
+}
+
+pub fn run_input(day: &Day, input: String, input_type: InputType) -> DayResult {
+    let answers = Answers::load(&input_type);
+    let report = get_input(input_type).and_then(|input| (day.execute)(&input));
+    match answers {
+        Ok(answers) => DayResult {
+            day: day.day,
+            input,
+            answers,
+            report,
+        },
+        Err(err) => DayResult {
+            day: day.day,
+            input,
+            answers: Answers::default(),
+            report: Err(err),
+        },
+    }
+}
+
+/// Inputs of a batch directory: every `.txt` file, plus the encrypted `.txt.enc` files that have
+/// no plain copy, sorted by name.
+fn inputs_in_dir(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut inputs = BTreeSet::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.to_string_lossy();
+        let encrypted_suffix = format!(".{}", common::crypto::ENCRYPTED_EXTENSION);
+        match name.strip_suffix(&encrypted_suffix) {
+            Some(plain) if plain.ends_with(".txt") => inputs.insert(PathBuf::from(plain)),
+            None if name.ends_with(".txt") => inputs.insert(path),
+            _ => false,
+        };
+    }
+    if inputs.is_empty() {
+        bail!("No .txt inputs in {}", dir.display());
+    }
+    Ok(inputs.into_iter().collect())
+}
+
+/// Applies `f` to every item, on as many threads as there are cores when `parallel` is set.
+/// Results keep the order of `items`.
+fn map_parallel<T: Sync, R: Send>(
+    items: &[T],
+    parallel: bool,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    if !parallel {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let threads = thread::available_parallelism()
+        .map_or(1, NonZero::get)
+        .min(items.len());
+    let mut results = thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = vec![];
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        results.push((index, f(item)));
+                    }
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Runner thread panicked"))
+            .collect::<Vec<_>>()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Serializable form of a [`DayResult`], printed by `--format json`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DayRecord {
+    pub day: u8,
+    pub input: String,
+    pub error: Option<String>,
+    pub parse_time: Option<Duration>,
+    pub parts: [PartRecord; 2],
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartRecord {
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub expected: Option<String>,
+    pub time: Option<Duration>,
+    pub status: Status,
+}
+
+impl From<&DayResult> for DayRecord {
+    fn from(result: &DayResult) -> Self {
+        let statuses = result.statuses();
+        let expected = [&result.answers.part_1, &result.answers.part_2];
+        let parts = [0, 1].map(|part| {
+            let report = result.report.as_ref().ok().map(|report| match part {
+                0 => &report.part_1,
+                _ => &report.part_2,
+            });
+            PartRecord {
+                answer: report.and_then(|report| report.answer.as_ref().ok().cloned()),
+                error: report
+                    .and_then(|report| report.answer.as_ref().err().map(|err| err.to_string())),
+                expected: expected[part].clone(),
+                time: report.map(|report| report.time),
+                status: statuses[part],
+            }
+        });
+        DayRecord {
+            day: result.day,
+            input: result.input.clone(),
+            error: result.report.as_ref().err().map(|err| err.to_string()),
+            parse_time: result.report.as_ref().ok().map(|report| report.parse_time),
+            parts,
+        }
+    }
+}
+
+pub fn print_results(results: &[DayResult]) {
+    let rows = results
+        .iter()
+        .map(|result| {
+            let mut row = vec![result.day.to_string(), result.input.clone()];
+            let statuses = result.statuses();
+            let expected = [&result.answers.part_1, &result.answers.part_2];
+            match &result.report {
+                Ok(report) => {
+                    row.push(format!("{:.2?}", report.parse_time));
+                    let parts = [&report.part_1, &report.part_2];
+                    for ((part, status), expected) in parts.into_iter().zip(statuses).zip(expected)
+                    {
+                        row.push(match &part.answer {
+                            Ok(answer) => answer.clone(),
+                            Err(err) => format!("error: {err}"),
+                        });
+                        row.push(format!("{:.2?}", part.time));
+                        row.push(status_cell(status, expected));
+                    }
+                }
+                Err(err) => {
+                    row.push(format!("error: {err}"));
+                    for (status, expected) in statuses.into_iter().zip(expected) {
+                        let status = status_cell(status, expected);
+                        row.extend([String::new(), String::new(), status]);
+                    }
+                }
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    print_table(
+        &[
+            "Day", "Input", "Parse", "Part 1", "Time", "Check", "Part 2", "Time", "Check",
+        ],
+        &rows,
+    );
+}
+
+pub fn status_cell(status: Status, expected: &Option<String>) -> String {
+    match (status, expected) {
+        (Status::Pass, _) => String::from("ok"),
+        (Status::Fail, Some(expected)) => format!("FAIL, expected {expected}"),
+        (Status::Fail, None) => String::from("FAIL"),
+        (Status::Unverified, _) => String::from("-"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        days,
+        run::{inputs_in_dir, map_parallel, run_input, Status},
+    };
+    use anyhow::anyhow;
+    use common::InputType;
+    use std::fs;
+
+    #[test]
+    fn test_check() {
+        let expected = String::from("143");
+        assert_eq!(
+            Status::check(None, &Ok(String::from("1"))),
+            Status::Unverified
+        );
+        assert_eq!(
+            Status::check(Some(&expected), &Ok(expected.clone())),
+            Status::Pass
+        );
+        assert_eq!(
+            Status::check(Some(&expected), &Ok(String::from("1"))),
+            Status::Fail
+        );
+        assert_eq!(
+            Status::check(Some(&expected), &Err(anyhow!("error"))),
+            Status::Fail
+        );
+        assert_eq!(Status::check(None, &Err(anyhow!("error"))), Status::Fail);
+    }
+
+    #[test]
+    fn test_inputs_dir() -> anyhow::Result<()> {
+        let day = days::find(1)?;
+        let example = fs::read_to_string(day.dir().join("test.txt"))?;
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("alice.txt"), &example)?;
+        fs::write(dir.path().join("alice.answers"), "part1: 11\npart2: 31\n")?;
+        fs::write(dir.path().join("bob.txt"), &example)?;
+        fs::write(dir.path().join("bob.answers"), "part1: 12\n")?;
+        fs::write(dir.path().join("carol.txt"), "not a day 1 input")?;
+        fs::write(dir.path().join("dave.txt"), &example)?;
+        fs::write(dir.path().join("notes.md"), "")?;
+
+        let inputs = inputs_in_dir(dir.path())?;
+        assert_eq!(inputs.len(), 4);
+        let results = map_parallel(&inputs, true, |path| {
+            run_input(day, String::new(), InputType::Input(path.clone()))
+        });
+        // An input that doesn't parse fails even without answers to check against
+        let failed = results
+            .iter()
+            .map(|result| result.failed())
+            .collect::<Vec<_>>();
+        assert_eq!(failed, [false, true, true, false]);
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::days;
+use anyhow::anyhow;
+use clap::Args;
+use serde::Serialize;
+use std::{
+    any::Any,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const YEAR: &str = "2024";
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Address to listen on, port 0 picks a free port
+    #[arg(long, default_value = "127.0.0.1:3024")]
+    address: String,
+}
+
+#[derive(Serialize)]
+struct AnswerResponse {
+    day: u8,
+    part: u8,
+    answer: String,
+    parse_time_ns: u64,
+    part_time_ns: u64,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Serves `POST /2024/day/<day>/part/<part>` with the input as body, one request at a time. A
+/// solver that panics answers 500 and the server keeps going, as long as panics unwind.
+pub fn serve(args: ServeArgs) -> anyhow::Result<()> {
+    let server = Server::http(&args.address).map_err(|err| anyhow!(err))?;
+    println!("Listening on http://{}", server.server_addr());
+    io::stdout().flush()?;
+
+    for mut request in server.incoming_requests() {
+        let (status, body) = match handle(&mut request) {
+            Ok(answer) => (200, serde_json::to_string(&answer)?),
+            Err((status, error)) => (status, serde_json::to_string(&ErrorResponse { error })?),
+        };
+        let header = Header::from_bytes("Content-Type", "application/json")
+            .map_err(|_| anyhow!("Invalid header"))?;
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(header);
+        if let Err(err) = request.respond(response) {
+            eprintln!("Couldn't respond: {err}");
+        }
+    }
+    Ok(())
+}
+
+fn handle(request: &mut Request) -> Result<AnswerResponse, (u16, String)> {
+    let not_found = || (404, format!("No puzzle at {}", request.url()));
+    let segments = request
+        .url()
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+    let [YEAR, "day", day, "part", part] = segments.as_slice() else {
+        return Err(not_found());
+    };
+    let day = day.parse::<u8>().ok().and_then(|day| days::find(day).ok());
+    let part = part.parse::<u8>().ok().filter(|part| matches!(part, 1 | 2));
+    let (Some(day), Some(part)) = (day, part) else {
+        return Err(not_found());
+    };
+    if *request.method() != Method::Post {
+        return Err((405, String::from("Send the input with POST")));
+    }
+
+    let mut input = String::new();
+    request
+        .as_reader()
+        .read_to_string(&mut input)
+        .map_err(|err| (400, format!("Couldn't read input: {err}")))?;
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| (day.execute_part)(&input, part)))
+        .map_err(panicked)?;
+    let (parse_time, report) = solved.map_err(|err| (422, format!("{err:#}")))?;
+    let answer = report.answer.map_err(|err| (422, format!("{err:#}")))?;
+
+    Ok(AnswerResponse {
+        day: day.day,
+        part,
+        answer,
+        parse_time_ns: parse_time.as_nanos() as u64,
+        part_time_ns: report.time.as_nanos() as u64,
+    })
+}
+
+fn panicked(payload: Box<dyn Any + Send>) -> (u16, String) {
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("unknown", String::as_str),
+    };
+    (500, format!("Solver panicked: {message}"))
+}
@@ -0,0 +1,43 @@
+use crate::days;
+use anyhow::{anyhow, bail};
+use clap::Args;
+use common::snapshot::ACCEPT_ENV;
+use std::{env, ffi::OsString, path::Path, process::Command};
+
+#[derive(Args)]
+pub struct SnapshotArgs {
+    /// Only check this day's snapshots
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Write the current parser output as the new snapshots
+    #[arg(long)]
+    accept: bool,
+}
+
+/// Runs the snapshot tests, which compare each day's parsed example with `snapshots/*.snap`.
+pub fn snapshot(args: SnapshotArgs) -> anyhow::Result<()> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .ok_or(anyhow!("Runner crate has no workspace"))?;
+
+    let cargo = env::var_os("CARGO").unwrap_or(OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command.current_dir(workspace).args(["test", "--quiet"]);
+    match args.day {
+        Some(day) => command.args(["--package", &format!("day{}", days::find(day)?.day)]),
+        None => command.arg("--workspace"),
+    };
+    command.args(["--lib", "test_snapshot"]);
+    if args.accept {
+        command.env(ACCEPT_ENV, "1");
+    }
+
+    if !command.status()?.success() {
+        bail!("Snapshots don't match, check the diffs above");
+    }
+    if args.accept {
+        println!("Snapshots written, review them with `git diff -- days/*/snapshots`");
+    }
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use crate::{
+    days::{Day, DAYS},
+    run::{self, status_cell, DayResult},
+};
+use common::{get_input, GridView};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, List, ListState, Paragraph},
+    DefaultTerminal, Frame,
+};
+use std::collections::HashSet;
+
+const HELP: &str =
+    " ↑/↓ day  enter run  tab example/real input  p highlighted part  h/j/k/l scroll  q quit ";
+
+#[derive(Default)]
+struct App {
+    days: ListState,
+    example: bool,
+    result: Option<DayResult>,
+    grid: Option<anyhow::Result<GridView>>,
+    /// Part whose cells are highlighted in the grid, 0 or 1
+    part: usize,
+    scroll: (usize, usize),
+}
+
+pub fn tui() -> anyhow::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = App::new().run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+impl App {
+    fn new() -> Self {
+        App {
+            days: ListState::default().with_selected(Some(0)),
+            example: true,
+            ..App::default()
+        }
+    }
+
+    fn day(&self) -> &'static Day {
+        &DAYS[self.days.selected().unwrap_or_default()]
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> anyhow::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Up => self.select(DAYS.len() - 1),
+                KeyCode::Down => self.select(1),
+                KeyCode::Enter => self.solve(),
+                KeyCode::Tab => {
+                    self.example = !self.example;
+                    self.clear();
+                }
+                KeyCode::Char('p') => self.part = 1 - self.part,
+                KeyCode::Char('h') => self.scroll.0 = self.scroll.0.saturating_sub(4),
+                KeyCode::Char('l') => self.scroll.0 += 4,
+                KeyCode::Char('k') => self.scroll.1 = self.scroll.1.saturating_sub(4),
+                KeyCode::Char('j') => self.scroll.1 += 4,
+                _ => {}
+            }
+        }
+    }
+
+    /// Moves the selection by `step` days, wrapping around.
+    fn select(&mut self, step: usize) {
+        let selected = self.days.selected().unwrap_or_default();
+        self.days.select(Some((selected + step) % DAYS.len()));
+        self.clear();
+    }
+
+    fn clear(&mut self) {
+        self.result = None;
+        self.grid = None;
+        self.scroll = (0, 0);
+    }
+
+    fn solve(&mut self) {
+        let day = self.day();
+        let inputs = (day.inputs)();
+        let (input, input_type) = match self.example {
+            true => ("example", inputs.example),
+            false => ("input", inputs.input),
+        };
+        self.result = Some(run::run_input(day, input.to_string(), input_type.clone()));
+        self.grid = get_input(input_type)
+            .and_then(|input| (day.grid_view)(&input))
+            .transpose();
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [list, right] =
+            Layout::horizontal([Constraint::Length(12), Constraint::Min(0)]).areas(main);
+        let [results, grid] =
+            Layout::vertical([Constraint::Length(7), Constraint::Min(0)]).areas(right);
+
+        let days = List::new(DAYS.iter().map(|day| format!("Day {}", day.day)))
+            .block(Block::bordered().title(" Days "))
+            .highlight_style(Style::new().reversed());
+        frame.render_stateful_widget(days, list, &mut self.days);
+
+        frame.render_widget(self.results(), results);
+        self.draw_grid(frame, grid);
+        frame.render_widget(Line::from(HELP).dim(), help);
+    }
+
+    fn results(&self) -> Paragraph<'static> {
+        let input = match self.example {
+            true => "example",
+            false => "real",
+        };
+        let block = Block::bordered().title(format!(" Day {}, {input} input ", self.day().day));
+        let Some(result) = &self.result else {
+            return Paragraph::new("Press enter to run").block(block);
+        };
+        let report = match &result.report {
+            Ok(report) => report,
+            Err(err) => return Paragraph::new(format!("error: {err:#}").red()).block(block),
+        };
+
+        let mut lines = vec![Line::from(format!("Parse   {:.2?}", report.parse_time))];
+        let expected = [&result.answers.part_1, &result.answers.part_2];
+        let parts = [&report.part_1, &report.part_2];
+        for (index, ((part, status), expected)) in parts
+            .into_iter()
+            .zip(result.statuses())
+            .zip(expected)
+            .enumerate()
+        {
+            let answer = match &part.answer {
+                Ok(answer) => Span::from(answer.clone()).bold(),
+                Err(err) => Span::from(format!("error: {err}")).red(),
+            };
+            lines.push(Line::from(vec![
+                Span::from(format!("Part {}  ", index + 1)),
+                answer,
+                Span::from(format!("  {:.2?}  ", part.time)),
+                Span::from(status_cell(status, expected)),
+            ]));
+        }
+        Paragraph::new(lines).block(block)
+    }
+
+    fn draw_grid(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(format!(" Grid, part {} highlighted ", self.part + 1));
+        let view = match &self.grid {
+            None => return frame.render_widget(Paragraph::new("No grid").block(block), area),
+            Some(Err(err)) => {
+                let error = Paragraph::new(format!("error: {err:#}").red());
+                return frame.render_widget(error.block(block), area);
+            }
+            Some(Ok(view)) => view,
+        };
+
+        let highlights = match self.part {
+            0 => &view.part_1,
+            _ => &view.part_2,
+        }
+        .iter()
+        .copied()
+        .collect::<HashSet<_>>();
+        let (scroll_x, scroll_y) = self.scroll;
+        let lines = view
+            .rows
+            .iter()
+            .enumerate()
+            .skip(scroll_y)
+            .take(area.height as usize)
+            .map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .skip(scroll_x)
+                    .take(area.width as usize)
+                    .map(|(x, char)| match highlights.contains(&(x, y)) {
+                        true => Span::from(char.to_string()).fg(Color::Yellow).bold(),
+                        false => Span::from(char.to_string()).dim(),
+                    })
+                    .collect::<Line>()
+            })
+            .collect::<Vec<_>>();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::App;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_draw_grid() -> anyhow::Result<()> {
+        let mut app = App::new();
+        // Day 4
+        app.select(3);
+        app.solve();
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24))?;
+        terminal.draw(|frame| app.draw(frame))?;
+        let screen = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+
+        assert!(screen.contains("Day 4, example input"));
+        assert!(screen.contains("Part 1  18"));
+        assert!(screen.contains("MMMSXXMASM"));
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    days,
+    run::{status_cell, DayRecord, PartRecord},
+    table::print_table,
+};
+use anyhow::{anyhow, Context};
+use clap::Args;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    env,
+    ffi::OsString,
+    path::Path,
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    #[arg(short, long)]
+    day: u8,
+    /// Build and run in release mode
+    #[arg(long)]
+    release: bool,
+}
+
+/// Editors save in several steps, changes closer than this are handled as one.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+pub fn watch(args: WatchArgs) -> anyhow::Result<()> {
+    let day = days::find(args.day)?;
+    let dir = day.dir();
+    let workspace = dir
+        .ancestors()
+        .nth(2)
+        .ok_or(anyhow!("Day directory has no workspace"))?;
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(&dir.join("src"), RecursiveMode::Recursive)?;
+    // Inputs, answers and the manifest
+    watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+    let mut previous = None;
+    loop {
+        println!("Running day {}", day.day);
+        match run_child(workspace, day.day, args.release) {
+            Ok(current) => {
+                print_diff(previous.as_ref(), &current);
+                previous = Some(current);
+            }
+            Err(err) => eprintln!("{err:#}"),
+        }
+        println!("Watching {} for changes", dir.display());
+        wait_for_change(&receiver)?;
+    }
+}
+
+fn wait_for_change(receiver: &Receiver<notify::Result<Event>>) -> anyhow::Result<()> {
+    loop {
+        let event = receiver.recv()??;
+        if matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            break;
+        }
+    }
+    while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+    Ok(())
+}
+
+/// Runs the day on the example and the real input through `cargo run`, so that it's rebuilt
+/// from the current sources first. Compiler output goes straight to the terminal.
+fn run_child(workspace: &Path, day: u8, release: bool) -> anyhow::Result<[DayRecord; 2]> {
+    let cargo = env::var_os("CARGO").unwrap_or(OsString::from("cargo"));
+    let run = |example: bool| -> anyhow::Result<DayRecord> {
+        let mut command = Command::new(&cargo);
+        command
+            .current_dir(workspace)
+            .args(["run", "--quiet", "--package", "aoc"]);
+        if release {
+            command.arg("--release");
+        }
+        command.args(["--", "run", "--format", "json", "--day", &day.to_string()]);
+        if example {
+            command.arg("--example");
+        }
+        let output = command.stderr(Stdio::inherit()).output()?;
+        let mut records: Vec<DayRecord> =
+            serde_json::from_slice(&output.stdout).context("Build failed")?;
+        records.pop().ok_or(anyhow!("Day {day} didn't report"))
+    };
+    Ok([run(true)?, run(false)?])
+}
+
+fn print_diff(previous: Option<&[DayRecord; 2]>, current: &[DayRecord; 2]) {
+    let mut rows = vec![];
+    for (index, (record, input)) in current.iter().zip(["example", "input"]).enumerate() {
+        let previous = previous.map(|previous| &previous[index]);
+        for part in 0..2 {
+            let previous = previous.map(|previous| &previous.parts[part]);
+            let current = &record.parts[part];
+            rows.push(vec![
+                String::from(input),
+                (part + 1).to_string(),
+                answer_cell(record, current),
+                status_cell(current.status, &current.expected),
+                current
+                    .time
+                    .map(|time| format!("{time:.2?}"))
+                    .unwrap_or_default(),
+                previous
+                    .map(|previous| change_cell(previous, current))
+                    .unwrap_or_default(),
+            ]);
+        }
+    }
+    print_table(
+        &["Input", "Part", "Answer", "Check", "Time", "Change"],
+        &rows,
+    );
+}
+
+fn answer_cell(record: &DayRecord, part: &PartRecord) -> String {
+    match (&record.error, &part.answer, &part.error) {
+        (Some(err), _, _) | (None, _, Some(err)) => format!("error: {err}"),
+        (None, Some(answer), None) => answer.clone(),
+        (None, None, None) => String::new(),
+    }
+}
+
+fn change_cell(previous: &PartRecord, current: &PartRecord) -> String {
+    let mut changes = vec![];
+    if previous.answer != current.answer {
+        changes.push(match &previous.answer {
+            Some(answer) => format!("answer was {answer}"),
+            None => String::from("answer is new"),
+        });
+    }
+    if let (Some(previous), Some(current)) = (previous.time, current.time) {
+        let ratio = current.as_secs_f64() / previous.as_secs_f64().max(f64::MIN_POSITIVE);
+        changes.push(format!("time {:+.0}%", (ratio - 1.0) * 100.0));
+    }
+    changes.join(", ")
+}
@@ -0,0 +1,34 @@
+use std::process::Command;
+
+#[test]
+fn test_example() -> anyhow::Result<()> {
+    let path = format!("{}/../day5/test.txt", env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["identify", &path])
+        .output()?;
+    assert!(output.status.success());
+    let output = String::from_utf8(output.stdout)?;
+    // The best row of the table and the verdict below it show the same confidence
+    let confidence = output
+        .lines()
+        .find(|line| line.starts_with("5 "))
+        .and_then(|line| line.rsplit('|').next())
+        .map(str::trim)
+        .ok_or(anyhow::anyhow!("No row for day 5 in {output}"))?;
+    assert!(
+        output.contains(&format!("Most plausible: day 5 ({confidence} confidence)")),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unknown() -> anyhow::Result<()> {
+    let path = format!("{}/Cargo.toml", env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["identify", &path])
+        .output()?;
+    assert!(!output.status.success());
+    assert!(!String::from_utf8(output.stdout)?.contains("Most plausible"));
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use serde_json::Value;
+use std::{
+    fs,
+    io::{BufRead, BufReader},
+    process::{Child, Command, Stdio},
+};
+
+/// `aoc serve` on a free port, killed when dropped.
+struct Server {
+    child: Child,
+    url: String,
+}
+
+impl Server {
+    fn start() -> anyhow::Result<Self> {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--address", "127.0.0.1:0"])
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdout = child.stdout.take().ok_or(anyhow::anyhow!("No stdout"))?;
+        let mut line = String::new();
+        BufReader::new(stdout).read_line(&mut line)?;
+        let url = line
+            .trim()
+            .strip_prefix("Listening on ")
+            .ok_or(anyhow::anyhow!("Unexpected output: {line}"))?;
+        Ok(Server {
+            url: url.to_string(),
+            child,
+        })
+    }
+
+    fn post(&self, path: &str, input: &str) -> (u16, Value) {
+        let response = match ureq::post(&format!("{}{path}", self.url)).send_string(input) {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => panic!("Request failed: {err}"),
+        };
+        let status = response.status();
+        (status, response.into_json().expect("JSON response"))
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn example(day: u8) -> String {
+    let path = format!("{}/../day{day}/test.txt", env!("CARGO_MANIFEST_DIR"));
+    fs::read_to_string(path).expect("example input")
+}
+
+#[test]
+fn test_answers() -> anyhow::Result<()> {
+    let server = Server::start()?;
+
+    let (status, body) = server.post("/2024/day/1/part/1", &example(1));
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "11");
+    assert_eq!(body["day"], 1);
+    assert!(body["parse_time_ns"].is_u64());
+
+    let (status, body) = server.post("/2024/day/5/part/2", &example(5));
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "123");
+    assert_eq!(body["part"], 2);
+    Ok(())
+}
+
+#[test]
+fn test_errors() -> anyhow::Result<()> {
+    let server = Server::start()?;
+
+    let (status, _) = server.post("/2023/day/1/part/1", &example(1));
+    assert_eq!(status, 404);
+    let (status, _) = server.post("/2024/day/30/part/1", &example(1));
+    assert_eq!(status, 404);
+    let (status, _) = server.post("/2024/day/1/part/3", &example(1));
+    assert_eq!(status, 404);
+
+    let (status, body) = server.post("/2024/day/1/part/1", "not a list of pairs");
+    assert_eq!(status, 422);
+    assert!(body["error"].is_string());
+
+    let response = ureq::get(&format!("{}/2024/day/1/part/1", server.url)).call();
+    assert!(matches!(response, Err(ureq::Error::Status(405, _))));
+    Ok(())
+}
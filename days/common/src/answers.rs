@@ -0,0 +1,73 @@
+use crate::InputType;
+use anyhow::bail;
+use std::{fs, path::PathBuf};
+
+/// Expected answers of an input, read from the `.answers` file next to it:
+///
+/// ```text
+/// part1: 11
+/// part2: 31
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut answers = Answers::default();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let Some((part, answer)) = line.split_once(':') else {
+                bail!("Expected `part1: <answer>`, found `{line}`");
+            };
+            let answer = Some(String::from(answer.trim()));
+            match part.trim() {
+                "part1" => answers.part_1 = answer,
+                "part2" => answers.part_2 = answer,
+                other => bail!("Unknown part `{other}`"),
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Loads the answers of `input_type`, empty if there is no `.answers` file for it.
+    pub fn load(input_type: &InputType) -> anyhow::Result<Self> {
+        if let InputType::Embedded { answers, .. } = input_type {
+            return Answers::parse(answers.unwrap_or_default());
+        }
+        match answers_path(input_type) {
+            Some(path) if path.exists() => Answers::parse(&fs::read_to_string(path)?),
+            _ => Ok(Answers::default()),
+        }
+    }
+}
+
+fn answers_path(input_type: &InputType) -> Option<PathBuf> {
+    match input_type {
+        InputType::Test => Some(PathBuf::from("./test.answers")),
+        InputType::Input(path) => Some(path.with_extension("answers")),
+        InputType::Embedded { .. } | InputType::Missing => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::Answers, InputType};
+
+    #[test]
+    fn test_parse() -> anyhow::Result<()> {
+        let answers = Answers::parse("part1: 11\n\npart2:  31 \n")?;
+        assert_eq!(answers.part_1.as_deref(), Some("11"));
+        assert_eq!(answers.part_2.as_deref(), Some("31"));
+        assert_eq!(Answers::parse("part1: 2\n")?.part_2, None);
+        assert!(Answers::parse("part3: 1").is_err());
+
+        let embedded = InputType::Embedded {
+            input: "",
+            answers: Some("part1: 11\n"),
+        };
+        assert_eq!(Answers::load(&embedded)?.part_1.as_deref(), Some("11"));
+        Ok(())
+    }
+}
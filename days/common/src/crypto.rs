@@ -0,0 +1,146 @@
+use anyhow::{anyhow, bail, Context};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    XChaCha20Poly1305, XNonce,
+};
+use std::{
+    env,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable holding the hex encoded input key.
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+/// Environment variable holding the path to a file with the hex encoded input key.
+pub const KEY_FILE_ENV: &str = "AOC_INPUT_KEY_FILE";
+/// Key file looked up in the current directory and its ancestors.
+pub const KEY_FILE_NAME: &str = ".aoc-key";
+/// Extension appended to encrypted inputs, `input.txt` becomes `input.txt.enc`.
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+const NONCE_LEN: usize = 24;
+
+pub struct InputKey([u8; 32]);
+
+impl InputKey {
+    pub fn generate() -> Self {
+        InputKey(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> anyhow::Result<Self> {
+        let mut key = [0u8; 32];
+        hex::decode_to_slice(hex.trim(), &mut key)
+            .context("Input key must be 64 hexadecimal characters")?;
+        Ok(InputKey(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    /// Loads the key from [`KEY_ENV`], then [`KEY_FILE_ENV`], then the first [`KEY_FILE_NAME`]
+    /// found walking up from the current directory.
+    pub fn load() -> anyhow::Result<Self> {
+        if let Ok(hex) = env::var(KEY_ENV) {
+            return InputKey::from_hex(&hex);
+        }
+        let path = match env::var_os(KEY_FILE_ENV) {
+            Some(path) => PathBuf::from(path),
+            None => find_key_file().ok_or(anyhow!(
+                "No input key found, set {KEY_ENV}, {KEY_FILE_ENV} or create {KEY_FILE_NAME}"
+            ))?,
+        };
+        let hex = fs::read_to_string(&path)
+            .with_context(|| format!("Couldn't read key file {}", path.display()))?;
+        InputKey::from_hex(&hex)
+    }
+
+    /// Returns the random nonce followed by the ciphertext.
+    pub fn encrypt(&self, plaintext: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let cipher = XChaCha20Poly1305::new(&self.0.into());
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| anyhow!("Encryption failed"))?;
+        let mut output = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        output.extend_from_slice(&nonce);
+        output.extend_from_slice(&ciphertext);
+        Ok(output)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        if data.len() < NONCE_LEN {
+            bail!("Encrypted input is too short");
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let cipher = XChaCha20Poly1305::new(&self.0.into());
+        cipher
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("Decryption failed, wrong key or corrupted input"))
+    }
+}
+
+pub fn find_key_file() -> Option<PathBuf> {
+    let current_dir = env::current_dir().ok()?;
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join(KEY_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(".");
+    encrypted.push(ENCRYPTED_EXTENSION);
+    PathBuf::from(encrypted)
+}
+
+/// The plain path of an encrypted input, nothing when `path` doesn't end in `.enc`.
+pub fn decrypted_path(path: &Path) -> Option<PathBuf> {
+    (path.extension() == Some(OsStr::new(ENCRYPTED_EXTENSION))).then(|| path.with_extension(""))
+}
+
+/// Decrypts `path` with the key from [`InputKey::load`].
+pub fn decrypt_file(path: &Path) -> anyhow::Result<String> {
+    decrypt_file_with(&InputKey::load()?, path)
+}
+
+pub fn decrypt_file_with(key: &InputKey, path: &Path) -> anyhow::Result<String> {
+    let _span = tracing::info_span!("decrypt_file").entered();
+    let data = fs::read(path)?;
+    let plaintext = key.decrypt(&data)?;
+    Ok(String::from_utf8(plaintext)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::{decrypted_path, encrypted_path, InputKey};
+    use std::path::Path;
+
+    #[test]
+    fn test_roundtrip() -> anyhow::Result<()> {
+        let key = InputKey::generate();
+        let encrypted = key.encrypt(b"3   4\n4   3\n")?;
+        assert_eq!(key.decrypt(&encrypted)?, b"3   4\n4   3\n");
+        assert!(InputKey::generate().decrypt(&encrypted).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex() -> anyhow::Result<()> {
+        let key = InputKey::generate();
+        assert_eq!(InputKey::from_hex(&key.to_hex())?.0, key.0);
+        assert!(InputKey::from_hex("abc").is_err());
+        assert_eq!(
+            encrypted_path(Path::new("days/day5/input.txt")),
+            Path::new("days/day5/input.txt.enc")
+        );
+        assert_eq!(
+            decrypted_path(Path::new("days/day5/input.txt.enc")),
+            Some(Path::new("days/day5/input.txt").to_path_buf())
+        );
+        assert_eq!(decrypted_path(Path::new("days/day5/input.txt")), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states starts repeating: the state after step `start + length` is the one
+/// after step `start` again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Earliest step with the same state as `step`.
+    pub fn reduce(&self, step: usize) -> usize {
+        match step < self.start {
+            true => step,
+            false => self.start + (step - self.start) % self.length,
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, keeping two states at a time. The states must repeat eventually,
+/// it never returns otherwise.
+pub fn floyd<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Brent's algorithm, keeping two states at a time and stepping less than [`floyd`]. The states
+/// must repeat eventually, it never returns otherwise.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Remembers every state to spot the first repeat. Nothing if `step` ends the sequence before
+/// that, otherwise the cycle and every state up to the repeat.
+pub fn find_cycle<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<(Cycle, Vec<S>)> {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = states.len() - start;
+            return Some((Cycle { start, length }, states));
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next?;
+    }
+}
+
+/// State after `steps` steps, skipping the whole turns of the cycle once the states repeat.
+pub fn fast_forward<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    steps: usize,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    while states.len() < steps {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+            };
+            return states.swap_remove(cycle.reduce(steps));
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::{brent, fast_forward, find_cycle, floyd, Cycle};
+
+    fn step(&value: &u64) -> u64 {
+        (value * value + 1) % 255
+    }
+
+    #[test]
+    fn test_cycle() -> anyhow::Result<()> {
+        let mut states = vec![3];
+        for _ in 0..100 {
+            states.push(step(states.last().unwrap_or(&3)));
+        }
+        let (cycle, history) =
+            find_cycle(3, |value| Some(step(value))).ok_or(anyhow::anyhow!("No cycle"))?;
+        assert_eq!(floyd(3, step), cycle);
+        assert_eq!(brent(3, step), cycle);
+        assert_eq!(history, states[..cycle.start + cycle.length]);
+        assert_eq!(states[cycle.start], states[cycle.start + cycle.length]);
+        assert_ne!(
+            states[cycle.start - 1],
+            states[cycle.start + cycle.length - 1]
+        );
+        assert_eq!(
+            brent(0, |&value| (value + 1) % 5),
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+        assert_eq!(
+            find_cycle(0, |&value| (value < 10).then_some(value + 1)),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        let mut state = 3;
+        for steps in 0..100 {
+            assert_eq!(fast_forward(3, step, steps), state);
+            state = step(&state);
+        }
+        let cycle = Cycle {
+            start: 2,
+            length: 3,
+        };
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(1_000_000_000), 4);
+    }
+}
@@ -0,0 +1,71 @@
+//! Build script support for the `embed-inputs` feature of each day.
+
+use crate::crypto;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Writes `$OUT_DIR/inputs.rs`, included by [`crate::day_inputs`] when the calling crate has the
+/// `embed-inputs` feature enabled. The real input is decrypted from `input.txt.enc` if needed, and
+/// is [`crate::InputType::Missing`] when neither file exists, the binary never reads the disk.
+/// `test.answers` and `input.answers` are embedded along with their inputs.
+pub fn embed_inputs() -> anyhow::Result<()> {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return Ok(());
+    }
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?);
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    let example = manifest_dir.join("test.txt");
+    let input = manifest_dir.join("input.txt");
+    let encrypted = crypto::encrypted_path(&input);
+    let example_answers = manifest_dir.join("test.answers");
+    let input_answers = manifest_dir.join("input.answers");
+
+    // Cargo reruns the script while a listed file is missing, which catches the input appearing
+    for path in [
+        &example,
+        &input,
+        &encrypted,
+        &example_answers,
+        &input_answers,
+    ] {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    println!("cargo:rerun-if-env-changed={}", crypto::KEY_ENV);
+    println!("cargo:rerun-if-env-changed={}", crypto::KEY_FILE_ENV);
+
+    let input = if input.exists() {
+        Some(input)
+    } else if encrypted.exists() {
+        let decrypted = out_dir.join("input.txt");
+        fs::write(&decrypted, crypto::decrypt_file(&encrypted)?)?;
+        Some(decrypted)
+    } else {
+        println!("cargo:warning=No input.txt or input.txt.enc found, running the input will fail");
+        None
+    };
+
+    let source = format!(
+        "::common::DayInputs {{ example: {}, input: {} }}",
+        embedded(&example, &example_answers),
+        match &input {
+            Some(input) => embedded(input, &input_answers),
+            None => String::from("::common::InputType::Missing"),
+        }
+    );
+    fs::write(out_dir.join("inputs.rs"), source)?;
+    Ok(())
+}
+
+fn embedded(input: &Path, answers: &Path) -> String {
+    let answers = match answers.exists() {
+        true => format!("Some(include_str!({answers:?}))"),
+        false => String::from("None"),
+    };
+    format!(
+        "::common::InputType::Embedded {{ input: include_str!({input:?}), answers: {answers} }}"
+    )
+}
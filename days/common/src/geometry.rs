@@ -0,0 +1,246 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Position on a grid, y grows downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Displacement between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        (self - other).manhattan()
+    }
+}
+
+impl Vec2 {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Add<Vec2> for Point {
+    type Output = Point;
+
+    fn add(self, step: Vec2) -> Point {
+        Point::new(self.x + step.x, self.y + step.y)
+    }
+}
+
+impl AddAssign<Vec2> for Point {
+    fn add_assign(&mut self, step: Vec2) {
+        *self = *self + step;
+    }
+}
+
+impl Sub<Vec2> for Point {
+    type Output = Point;
+
+    fn sub(self, step: Vec2) -> Point {
+        Point::new(self.x - step.x, self.y - step.y)
+    }
+}
+
+impl SubAssign<Vec2> for Point {
+    fn sub_assign(&mut self, step: Vec2) {
+        *self = *self - step;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: isize) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+/// The four orthogonal directions, clockwise from up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Step of one cell in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction4::Up => Vec2::new(0, -1),
+            Direction4::Right => Vec2::new(1, 0),
+            Direction4::Down => Vec2::new(0, 1),
+            Direction4::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// The four orthogonal and four diagonal directions, clockwise from up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Step of one cell in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction8::Up => Vec2::new(0, -1),
+            Direction8::UpRight => Vec2::new(1, -1),
+            Direction8::Right => Vec2::new(1, 0),
+            Direction8::DownRight => Vec2::new(1, 1),
+            Direction8::Down => Vec2::new(0, 1),
+            Direction8::DownLeft => Vec2::new(-1, 1),
+            Direction8::Left => Vec2::new(-1, 0),
+            Direction8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Direction4, Direction8, Point, Vec2};
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.reverse(), Direction4::Right);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.reverse(), Direction8::DownRight);
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+        for direction in Direction8::ALL {
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+            assert_eq!(direction.turn_right().turn_left(), direction);
+        }
+        let point = Point::new(2, 3) + Direction4::Up.offset() * 3;
+        assert_eq!(point, Point::new(2, 0));
+        assert_eq!(point - Point::new(1, 1), Vec2::new(1, -1));
+        assert_eq!(point.manhattan(Point::ORIGIN), 2);
+    }
+}
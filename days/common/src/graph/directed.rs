@@ -0,0 +1,310 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+const UNVISITED: usize = usize::MAX;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    New,
+    Open,
+    Done,
+}
+
+/// Directed graph without parallel edges. Nodes are kept in insertion order and each one lists
+/// its successors and predecessors in the order their edges were added.
+#[derive(Clone, Debug)]
+pub struct DiGraph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl<N> Default for DiGraph<N> {
+    fn default() -> Self {
+        DiGraph {
+            nodes: vec![],
+            indices: HashMap::new(),
+            successors: vec![],
+            predecessors: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> DiGraph<N> {
+    pub fn new() -> Self {
+        DiGraph::default()
+    }
+
+    fn add_index(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.successors.push(vec![]);
+        self.predecessors.push(vec![]);
+        index
+    }
+
+    /// Adds `node` unless the graph has it already.
+    pub fn add_node(&mut self, node: N) {
+        self.add_index(node);
+    }
+
+    /// Adds an edge from `from` to `to`, and the nodes the graph doesn't have yet. Returns
+    /// whether the edge is new.
+    pub fn add_edge(&mut self, from: N, to: N) -> bool {
+        let from = self.add_index(from);
+        let to = self.add_index(to);
+        if self.successors[from].contains(&to) {
+            return false;
+        }
+        self.successors[from].push(to);
+        self.predecessors[to].push(from);
+        true
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.successors.iter().map(Vec::len).sum()
+    }
+
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        match (self.indices.get(from), self.indices.get(to)) {
+            (Some(&from), Some(to)) => self.successors[from].contains(to),
+            _ => false,
+        }
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.successors
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, successors)| {
+                successors
+                    .iter()
+                    .map(move |&to| (&self.nodes[from], &self.nodes[to]))
+            })
+    }
+
+    /// Nodes `node` has an edge to, none if the graph doesn't have it.
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.neighbours(&self.successors, node)
+    }
+
+    /// Nodes with an edge to `node`, none if the graph doesn't have it.
+    pub fn predecessors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.neighbours(&self.predecessors, node)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        adjacency: &'a [Vec<usize>],
+        node: &N,
+    ) -> impl Iterator<Item = &'a N> {
+        let indices = match self.indices.get(node) {
+            Some(&index) => adjacency[index].as_slice(),
+            None => &[],
+        };
+        indices.iter().map(|&index| &self.nodes[index])
+    }
+
+    /// Graph of `nodes` and the edges between them. Nodes this graph doesn't have are added
+    /// without edges.
+    pub fn induced_subgraph<'a>(&self, nodes: impl IntoIterator<Item = &'a N>) -> DiGraph<N>
+    where
+        N: 'a,
+    {
+        let mut subgraph = DiGraph::new();
+        for node in nodes {
+            subgraph.add_node(node.clone());
+        }
+        let kept = subgraph.nodes.clone();
+        let kept_set = kept.iter().collect::<HashSet<_>>();
+        for node in &kept {
+            for successor in self
+                .successors(node)
+                .filter(|successor| kept_set.contains(successor))
+            {
+                subgraph.add_edge(node.clone(), successor.clone());
+            }
+        }
+        subgraph
+    }
+
+    /// Nodes in an order where every edge goes forwards, using Kahn's algorithm. Nodes that are
+    /// free to go first keep their insertion order. A cycle when there's no such order.
+    pub fn toposort(&self) -> Result<Vec<N>, Vec<N>> {
+        let mut in_degrees = self.predecessors.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut ready = (0..self.nodes.len())
+            .filter(|&index| in_degrees[index] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = ready.pop_front() {
+            order.push(self.nodes[index].clone());
+            for &successor in &self.successors[index] {
+                in_degrees[successor] -= 1;
+                if in_degrees[successor] == 0 {
+                    ready.push_back(successor);
+                }
+            }
+        }
+        match order.len() == self.nodes.len() {
+            true => Ok(order),
+            false => Err(self.find_cycle().unwrap_or_default()),
+        }
+    }
+
+    /// Nodes of a cycle in edge order, the last one has an edge back to the first.
+    pub fn find_cycle(&self) -> Option<Vec<N>> {
+        let mut visits = vec![Visit::New; self.nodes.len()];
+        for root in 0..self.nodes.len() {
+            if visits[root] != Visit::New {
+                continue;
+            }
+            visits[root] = Visit::Open;
+            // The stack holds the current path, each node with the next successor to look at
+            let mut path = vec![(root, 0)];
+            while let Some((index, position)) = path.pop() {
+                let Some(&successor) = self.successors[index].get(position) else {
+                    visits[index] = Visit::Done;
+                    continue;
+                };
+                path.push((index, position + 1));
+                match visits[successor] {
+                    Visit::New => {
+                        visits[successor] = Visit::Open;
+                        path.push((successor, 0));
+                    }
+                    Visit::Open => {
+                        let start = path.iter().position(|&(index, _)| index == successor)?;
+                        let cycle = path[start..]
+                            .iter()
+                            .map(|&(index, _)| self.nodes[index].clone());
+                        return Some(cycle.collect());
+                    }
+                    Visit::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Strongly connected components using Tarjan's algorithm. Every edge between two
+    /// components goes from a later one to an earlier one, the reverse of a topological order.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let count = self.nodes.len();
+        let mut indices = vec![UNVISITED; count];
+        let mut low_links = vec![0; count];
+        let mut on_stack = vec![false; count];
+        let mut stack = vec![];
+        let mut next_index = 0;
+        let mut components = vec![];
+
+        for root in 0..count {
+            if indices[root] != UNVISITED {
+                continue;
+            }
+            // Recursion turned into a stack of nodes with the next successor to look at
+            let mut calls = vec![(root, 0)];
+            while let Some((node, position)) = calls.pop() {
+                if position == 0 {
+                    indices[node] = next_index;
+                    low_links[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+                if let Some(&successor) = self.successors[node].get(position) {
+                    calls.push((node, position + 1));
+                    if indices[successor] == UNVISITED {
+                        calls.push((successor, 0));
+                    } else if on_stack[successor] {
+                        low_links[node] = low_links[node].min(indices[successor]);
+                    }
+                    continue;
+                }
+                if low_links[node] == indices[node] {
+                    let mut component = vec![];
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(self.nodes[member].clone());
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+                if let Some(&(parent, _)) = calls.last() {
+                    low_links[parent] = low_links[parent].min(low_links[node]);
+                }
+            }
+        }
+        components
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for DiGraph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut graph = DiGraph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::DiGraph;
+
+    #[test]
+    fn test_graph() {
+        let mut graph = DiGraph::from_iter([(1, 2), (1, 3), (3, 2)]);
+        assert!(!graph.add_edge(1, 2));
+        graph.add_node(4);
+        assert_eq!((graph.node_count(), graph.edge_count()), (4, 3));
+        assert!(graph.contains_edge(&3, &2));
+        assert!(!graph.contains_edge(&2, &3));
+        assert_eq!(graph.successors(&1).collect::<Vec<_>>(), [&2, &3]);
+        assert_eq!(graph.predecessors(&2).collect::<Vec<_>>(), [&1, &3]);
+        assert_eq!(graph.successors(&9).count(), 0);
+        assert_eq!(graph.toposort(), Ok(vec![1, 4, 3, 2]));
+        assert_eq!(graph.find_cycle(), None);
+
+        let subgraph = graph.induced_subgraph(&[2, 3, 5]);
+        assert_eq!(subgraph.nodes().collect::<Vec<_>>(), [&2, &3, &5]);
+        assert_eq!(subgraph.edges().collect::<Vec<_>>(), [(&3, &2)]);
+    }
+
+    #[test]
+    fn test_cycles() {
+        let graph = DiGraph::from_iter([(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (6, 6)]);
+        let cycle = graph.toposort().err();
+        assert_eq!(cycle, Some(vec![1, 2, 3]));
+        let mut components = graph.strongly_connected_components();
+        assert_eq!(components.len(), 3);
+        // Later components only have edges to earlier ones
+        assert_eq!(components[0].len(), 2);
+        components.iter_mut().for_each(|component| component.sort());
+        components.sort();
+        assert_eq!(components, [vec![1, 2, 3], vec![4, 5], vec![6]]);
+        let loop_only = DiGraph::from_iter([(6, 6)]);
+        assert_eq!(loop_only.find_cycle(), Some(vec![6]));
+    }
+}
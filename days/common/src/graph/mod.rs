@@ -0,0 +1,7 @@
+mod directed;
+mod undirected;
+mod union_find;
+
+pub use directed::DiGraph;
+pub use undirected::UnGraph;
+pub use union_find::UnionFind;
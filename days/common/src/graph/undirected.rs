@@ -0,0 +1,255 @@
+use crate::graph::UnionFind;
+use std::{
+    collections::{BTreeSet, HashMap},
+    hash::Hash,
+};
+
+/// Undirected graph without loops or parallel edges. Nodes are kept in insertion order and
+/// neighbours are sorted by it, so every result comes out in the same order on each run.
+#[derive(Clone, Debug)]
+pub struct UnGraph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    neighbours: Vec<BTreeSet<usize>>,
+}
+
+impl<N> Default for UnGraph<N> {
+    fn default() -> Self {
+        UnGraph {
+            nodes: vec![],
+            indices: HashMap::new(),
+            neighbours: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> UnGraph<N> {
+    pub fn new() -> Self {
+        UnGraph::default()
+    }
+
+    fn add_index(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.neighbours.push(BTreeSet::new());
+        index
+    }
+
+    /// Adds `node` unless the graph has it already.
+    pub fn add_node(&mut self, node: N) {
+        self.add_index(node);
+    }
+
+    /// Adds an edge between `first` and `second`, and the nodes the graph doesn't have yet.
+    /// Returns whether the edge is new, an edge from a node to itself is never added.
+    pub fn add_edge(&mut self, first: N, second: N) -> bool {
+        let first = self.add_index(first);
+        let second = self.add_index(second);
+        if first == second {
+            return false;
+        }
+        self.neighbours[second].insert(first);
+        self.neighbours[first].insert(second)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.neighbours.iter().map(BTreeSet::len).sum::<usize>() / 2
+    }
+
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    pub fn contains_edge(&self, first: &N, second: &N) -> bool {
+        match (self.indices.get(first), self.indices.get(second)) {
+            (Some(&first), Some(second)) => self.neighbours[first].contains(second),
+            _ => false,
+        }
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Each edge once, the node added first on the left.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.neighbours
+            .iter()
+            .enumerate()
+            .flat_map(move |(first, neighbours)| {
+                neighbours
+                    .range(first + 1..)
+                    .map(move |&second| (&self.nodes[first], &self.nodes[second]))
+            })
+    }
+
+    /// Nodes sharing an edge with `node`, none if the graph doesn't have it.
+    pub fn neighbours(&self, node: &N) -> impl Iterator<Item = &N> {
+        let neighbours = self.indices.get(node).map(|&index| &self.neighbours[index]);
+        neighbours
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.nodes[index])
+    }
+
+    pub fn degree(&self, node: &N) -> usize {
+        self.indices
+            .get(node)
+            .map_or(0, |&index| self.neighbours[index].len())
+    }
+
+    fn to_nodes(&self, indices: &[usize]) -> Vec<N> {
+        indices
+            .iter()
+            .map(|&index| self.nodes[index].clone())
+            .collect()
+    }
+
+    /// Nodes linked to each other by paths, components ordered by their first node.
+    pub fn connected_components(&self) -> Vec<Vec<N>> {
+        let mut sets = UnionFind::new(self.nodes.len());
+        for (first, neighbours) in self.neighbours.iter().enumerate() {
+            for &second in neighbours.range(first + 1..) {
+                sets.union(first, second);
+            }
+        }
+        sets.sets().iter().map(|set| self.to_nodes(set)).collect()
+    }
+
+    /// Every three nodes that all share an edge, each triangle once.
+    pub fn triangles(&self) -> Vec<[N; 3]> {
+        let mut triangles = vec![];
+        for (first, neighbours) in self.neighbours.iter().enumerate() {
+            for &second in neighbours.range(first + 1..) {
+                let common = neighbours
+                    .range(second + 1..)
+                    .filter(|third| self.neighbours[second].contains(third));
+                for &third in common {
+                    triangles.push([first, second, third].map(|index| self.nodes[index].clone()));
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Every clique no other node can join, using Bron–Kerbosch with pivoting.
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        let mut cliques = vec![];
+        let candidates = (0..self.nodes.len()).collect();
+        self.bron_kerbosch(&mut vec![], candidates, BTreeSet::new(), &mut |clique| {
+            cliques.push(self.to_nodes(clique));
+        });
+        cliques
+    }
+
+    /// A largest clique, the first one found when several are as large.
+    pub fn maximum_clique(&self) -> Vec<N> {
+        let mut largest = vec![];
+        let candidates = (0..self.nodes.len()).collect();
+        self.bron_kerbosch(&mut vec![], candidates, BTreeSet::new(), &mut |clique| {
+            if clique.len() > largest.len() {
+                largest = clique.to_vec();
+            }
+        });
+        self.to_nodes(&largest)
+    }
+
+    /// Reports every maximal clique holding all of `clique`, some of `candidates` and none of
+    /// `excluded`. Only candidates away from the pivot are branched on, the pivot's neighbours
+    /// are reached through the branch that takes it or one of its other neighbours.
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: BTreeSet<usize>,
+        mut excluded: BTreeSet<usize>,
+        report: &mut impl FnMut(&[usize]),
+    ) {
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|&&node| candidates.intersection(&self.neighbours[node]).count());
+        let Some(&pivot) = pivot else {
+            report(clique);
+            return;
+        };
+        let branches = candidates
+            .difference(&self.neighbours[pivot])
+            .copied()
+            .collect::<Vec<_>>();
+        for node in branches {
+            let neighbours = &self.neighbours[node];
+            clique.push(node);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbours).copied().collect(),
+                excluded.intersection(neighbours).copied().collect(),
+                report,
+            );
+            clique.pop();
+            candidates.remove(&node);
+            excluded.insert(node);
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for UnGraph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut graph = UnGraph::new();
+        for (first, second) in edges {
+            graph.add_edge(first, second);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::UnGraph;
+
+    #[test]
+    fn test_components() {
+        let mut graph = UnGraph::from_iter([("a", "b"), ("c", "d"), ("b", "e"), ("b", "a")]);
+        assert!(!graph.add_edge("f", "f"));
+        assert_eq!((graph.node_count(), graph.edge_count()), (6, 3));
+        assert!(graph.contains_edge(&"e", &"b"));
+        assert_eq!(graph.neighbours(&"b").collect::<Vec<_>>(), [&"a", &"e"]);
+        assert_eq!(graph.degree(&"z"), 0);
+        assert_eq!(
+            graph.connected_components(),
+            [vec!["a", "b", "e"], vec!["c", "d"], vec!["f"]]
+        );
+    }
+
+    #[test]
+    fn test_cliques() {
+        // A square with both diagonals, plus a triangle hanging off one corner
+        let graph = UnGraph::from_iter([
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 1),
+            (1, 3),
+            (2, 4),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+        ]);
+        assert_eq!(graph.triangles().len(), 5);
+        assert!(graph.triangles().contains(&[4, 5, 6]));
+        let mut cliques = graph.maximal_cliques();
+        cliques.iter_mut().for_each(|clique| clique.sort());
+        cliques.sort();
+        assert_eq!(cliques, [vec![1, 2, 3, 4], vec![4, 5, 6]]);
+        let mut largest = graph.maximum_clique();
+        largest.sort();
+        assert_eq!(largest, [1, 2, 3, 4]);
+        assert!(UnGraph::<u8>::new().maximum_clique().is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+/// Disjoint sets of the elements `0..len`, merged by union by size with path halving.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    set_count: usize,
+}
+
+impl UnionFind {
+    /// Every element in a set of its own.
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            set_count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Representative of the set holding `element`.
+    pub fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            let grandparent = self.parents[self.parents[element]];
+            self.parents[element] = grandparent;
+            element = grandparent;
+        }
+        element
+    }
+
+    /// Merges the sets holding `first` and `second`, returning whether they were apart.
+    pub fn union(&mut self, first: usize, second: usize) -> bool {
+        let (mut first, mut second) = (self.find(first), self.find(second));
+        if first == second {
+            return false;
+        }
+        if self.sizes[first] < self.sizes[second] {
+            (first, second) = (second, first);
+        }
+        self.parents[second] = first;
+        self.sizes[first] += self.sizes[second];
+        self.set_count -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, first: usize, second: usize) -> bool {
+        self.find(first) == self.find(second)
+    }
+
+    /// Size of the set holding `element`.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Elements of each set, sets ordered by their smallest element.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut set_indices = vec![usize::MAX; self.len()];
+        let mut sets: Vec<Vec<usize>> = vec![];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if set_indices[root] == usize::MAX {
+                set_indices[root] = sets.len();
+                sets.push(vec![]);
+            }
+            sets[set_indices[root]].push(element);
+        }
+        sets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::UnionFind;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.set_count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(4, 1));
+        assert!(!sets.union(0, 4));
+        assert!(sets.union(2, 3));
+        assert!(sets.same_set(0, 4));
+        assert!(!sets.same_set(0, 2));
+        assert_eq!(sets.set_size(4), 3);
+        assert_eq!(sets.set_count(), 3);
+        assert_eq!(sets.sets(), [vec![0, 1, 4], vec![2, 3], vec![5]]);
+    }
+}
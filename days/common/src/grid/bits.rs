@@ -0,0 +1,351 @@
+use crate::{
+    geometry::{Direction4, Point},
+    grid::Grid,
+};
+use std::{
+    fmt::{self, Display},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Grid of booleans packed 64 to a `u64`. Each row starts on a new word so whole rows shift
+/// and combine a word at a time, the bits past the width are always clear.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    row_words: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let row_words = width.div_ceil(WORD_BITS);
+        BitGrid {
+            width,
+            height,
+            row_words,
+            words: vec![0; row_words * height],
+        }
+    }
+
+    /// Grid with a bit set for each cell of `grid` that `predicate` holds for.
+    pub fn from_grid<T>(grid: &Grid<T>, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        for (position, cell) in grid.iter() {
+            if predicate(cell) {
+                bits.set(Point::from(position));
+            }
+        }
+        bits
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, point: Point) -> Option<(usize, u64)> {
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        match x < self.width && y < self.height {
+            true => Some((y * self.row_words + x / WORD_BITS, 1 << (x % WORD_BITS))),
+            false => None,
+        }
+    }
+
+    /// Whether the bit at `point` is set, never outside the grid.
+    pub fn test(&self, point: Point) -> bool {
+        self.index(point)
+            .is_some_and(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// Sets the bit at `point`, returning whether it was clear.
+    pub fn set(&mut self, point: Point) -> bool {
+        let (word, bit) = self
+            .index(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"));
+        let was_clear = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        was_clear
+    }
+
+    /// Clears the bit at `point`, returning whether it was set.
+    pub fn clear(&mut self, point: Point) -> bool {
+        let (word, bit) = self
+            .index(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"));
+        let was_set = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        was_set
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Positions of the set bits in row order.
+    pub fn ones(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let y = index / self.row_words;
+                let x = index % self.row_words * WORD_BITS;
+                let mut word = word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Some(Point::from((x + bit, y)))
+                })
+            })
+    }
+
+    /// Every bit moved one cell towards `direction`, the ones leaving the grid are dropped.
+    pub fn shift(&self, direction: Direction4) -> BitGrid {
+        let mut shifted = BitGrid::new(self.width, self.height);
+        let row_words = self.row_words;
+        match direction {
+            Direction4::Up => {
+                let rows = row_words..self.words.len();
+                shifted.words[..rows.len()].copy_from_slice(&self.words[rows]);
+            }
+            Direction4::Down => {
+                let rows = ..self.words.len().saturating_sub(row_words);
+                shifted.words[row_words..].copy_from_slice(&self.words[rows]);
+            }
+            Direction4::Right => {
+                for (row, shifted_row) in
+                    self.rows().zip(shifted.words.chunks_mut(row_words.max(1)))
+                {
+                    let mut carry = 0;
+                    for (&word, shifted_word) in row.iter().zip(shifted_row) {
+                        *shifted_word = word << 1 | carry;
+                        carry = word >> (WORD_BITS - 1);
+                    }
+                }
+                shifted.clear_padding();
+            }
+            Direction4::Left => {
+                for (row, shifted_row) in
+                    self.rows().zip(shifted.words.chunks_mut(row_words.max(1)))
+                {
+                    let mut carry = 0;
+                    for (&word, shifted_word) in row.iter().zip(shifted_row).rev() {
+                        *shifted_word = word >> 1 | carry << (WORD_BITS - 1);
+                        carry = word & 1;
+                    }
+                }
+            }
+        }
+        shifted
+    }
+
+    /// The set bits and their orthogonal neighbours.
+    pub fn spread4(&self) -> BitGrid {
+        let mut spread = self.clone();
+        for direction in Direction4::ALL {
+            spread |= &self.shift(direction);
+        }
+        spread
+    }
+
+    /// Set bits orthogonally connected to `start` through set bits, nothing if `start` isn't set.
+    /// Grows the whole region a step at a time with word operations.
+    pub fn flood_fill(&self, start: Point) -> BitGrid {
+        let mut region = BitGrid::new(self.width, self.height);
+        if !self.test(start) {
+            return region;
+        }
+        region.set(start);
+        loop {
+            let mut grown = region.spread4();
+            grown &= self;
+            if grown == region {
+                return region;
+            }
+            region = grown;
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[u64]> {
+        self.words.chunks(self.row_words.max(1))
+    }
+
+    fn clear_padding(&mut self) {
+        let used = self.width % WORD_BITS;
+        if used == 0 {
+            return;
+        }
+        let mask = (1 << used) - 1;
+        for row in self.words.chunks_mut(self.row_words) {
+            if let Some(last) = row.last_mut() {
+                *last &= mask;
+            }
+        }
+    }
+
+    fn combine(&mut self, other: &BitGrid, operation: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "Bit grids must have the same size"
+        );
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word = operation(*word, other);
+        }
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, other: &BitGrid) {
+        self.combine(other, |word, other| word & other);
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, other: &BitGrid) {
+        self.combine(other, |word, other| word | other);
+    }
+}
+
+impl BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, other: &BitGrid) {
+        self.combine(other, |word, other| word ^ other);
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result &= other;
+        result
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result |= other;
+        result
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result ^= other;
+        result
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut result = self.clone();
+        for word in &mut result.words {
+            *word = !*word;
+        }
+        result.clear_padding();
+        result
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell = match self.test(Point::from((x, y))) {
+                    true => '#',
+                    false => '.',
+                };
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{Direction4, Point},
+        grid::{BitGrid, Grid},
+    };
+
+    fn walls(input: &str) -> anyhow::Result<BitGrid> {
+        Ok(BitGrid::from_grid(&Grid::parse(input)?, |&cell| {
+            cell == '#'
+        }))
+    }
+
+    #[test]
+    fn test_bits() -> anyhow::Result<()> {
+        let mut bits = BitGrid::new(70, 2);
+        assert!(bits.set(Point::new(63, 0)));
+        assert!(!bits.set(Point::new(63, 0)));
+        assert!(bits.set(Point::new(69, 1)));
+        assert!(bits.test(Point::new(63, 0)));
+        assert!(!bits.test(Point::new(64, 0)));
+        assert!(!bits.test(Point::new(-1, 0)));
+        assert_eq!(bits.count_ones(), 2);
+        assert_eq!(
+            bits.ones().collect::<Vec<_>>(),
+            [Point::new(63, 0), Point::new(69, 1)]
+        );
+
+        let right = bits.shift(Direction4::Right);
+        assert_eq!(right.ones().collect::<Vec<_>>(), [Point::new(64, 0)]);
+        assert_eq!(right.shift(Direction4::Left).count_ones(), 1);
+        assert!(right.shift(Direction4::Left).test(Point::new(63, 0)));
+        let down = bits.shift(Direction4::Down);
+        assert_eq!(down.ones().collect::<Vec<_>>(), [Point::new(63, 1)]);
+        assert_eq!(down.shift(Direction4::Up).count_ones(), 1);
+
+        assert_eq!((!&bits).count_ones(), 140 - 2);
+        assert_eq!((&bits & &down).count_ones(), 0);
+        assert_eq!((&bits | &down).count_ones(), 3);
+        assert_eq!((&bits ^ &bits).count_ones(), 0);
+        assert!(bits.clear(Point::new(63, 0)));
+        assert!(!bits.clear(Point::new(63, 0)));
+
+        let grid = walls("#.#\n.##\n")?;
+        assert_eq!(grid.to_string(), "#.#\n.##\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_flood_fill() -> anyhow::Result<()> {
+        let open = !&walls("..#..\n.##..\n#..#.\n..#..\n")?;
+        let region = open.flood_fill(Point::ORIGIN);
+        assert_eq!(region.to_string(), "##...\n#....\n.....\n.....\n");
+        let region = open.flood_fill(Point::new(4, 0));
+        assert_eq!(region.to_string(), "...##\n...##\n....#\n...##\n");
+        assert!(open.flood_fill(Point::new(2, 0)).is_empty());
+        Ok(())
+    }
+}
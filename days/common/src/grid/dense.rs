@@ -0,0 +1,416 @@
+use crate::{
+    geometry::Point,
+    grid::{Bounds, GridLike},
+};
+use anyhow::bail;
+use std::{
+    fmt::{self, Display},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+/// Rectangular grid stored row by row. `(0, 0)` is the top left cell and y grows downwards.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Grid with the cell at each `(x, y)` computed by `cell`.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid from its cells in row order.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> anyhow::Result<Self> {
+        if width == 0 || cells.is_empty() {
+            bail!("The grid is empty");
+        }
+        if !cells.len().is_multiple_of(width) {
+            bail!("{} cells don't fill rows of {width}", cells.len());
+        }
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Grid from its rows, which must all have the same width.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> anyhow::Result<Self> {
+        let mut rows = rows.into_iter();
+        let Some(mut cells) = rows.next() else {
+            bail!("The grid is empty");
+        };
+        let width = cells.len();
+        for (y, row) in rows.enumerate() {
+            if row.len() != width {
+                bail!(
+                    "Every row of the grid must be {width} wide, row {} is {}",
+                    y + 1,
+                    row.len()
+                );
+            }
+            cells.extend(row);
+        }
+        Grid::from_vec(width, cells)
+    }
+
+    /// Grid with a cell for each character of the lines of `input`.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Self> {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&mut cell).collect())
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        self.contains(point.x, point.y)
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    /// Cell at `(x, y)`, nothing outside the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        match self.contains(x, y) {
+            true => Some(&self.cells[y as usize * self.width + x as usize]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        match self.contains(x, y) {
+            true => Some(&mut self.cells[y as usize * self.width + x as usize]),
+            false => None,
+        }
+    }
+
+    /// Cells in row order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Cells in row order with their `(x, y)` position.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells from `(x, y)` on, one step of `(dx, dy)` at a time, until the edge of the grid.
+    pub fn ray(&self, x: isize, y: isize, dx: isize, dy: isize) -> impl Iterator<Item = &T> {
+        let mut position = (x, y);
+        iter::from_fn(move || {
+            let cell = self.get(position.0, position.1)?;
+            position = (position.0 + dx, position.1 + dy);
+            Some(cell)
+        })
+    }
+
+    /// Diagonals going down and right, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+        starts.map(|(x, y)| self.ray(x as isize, y as isize, 1, 1))
+    }
+
+    /// Diagonals going down and left, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width as isize - 1;
+        let starts = (0..self.width as isize)
+            .map(|x| (x, 0))
+            .chain((1..self.height as isize).map(move |y| (last, y)));
+        starts.map(|(x, y)| self.ray(x, y, -1, 1))
+    }
+
+    /// Cells diagonal after diagonal, in the order of [`Grid::diagonals`].
+    pub fn diagonal_major(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let (width, height) = (self.width, self.height);
+        let starts = (0..height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..width).map(|x| (x, 0)));
+        starts
+            .flat_map(move |(x, y)| {
+                (0..)
+                    .map(move |step| (x + step, y + step))
+                    .take_while(move |&(x, y)| x < width && y < height)
+            })
+            .map(|position| (position, &self[position]))
+    }
+
+    /// Grid of references to the cells paired with their position. Transforming it instead of
+    /// the grid is cheap and keeps track of where each cell came from.
+    pub fn enumerate(&self) -> Grid<((usize, usize), &T)> {
+        Grid::from_fn(self.width, self.height, |x, y| ((x, y), &self[(x, y)]))
+    }
+
+    /// Sub-grid of `width` by `height` cells with its top left corner at `(x, y)`, nothing if it
+    /// doesn't fit.
+    pub fn window(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        if x + width > self.width || y + height > self.height {
+            return None;
+        }
+        Some(Grid::from_fn(width, height, |dx, dy| {
+            self[(x + dx, y + dy)].clone()
+        }))
+    }
+
+    /// Every `width` by `height` sub-grid, in row order of their top left corner.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = Grid<T>> + '_
+    where
+        T: Clone,
+    {
+        let columns = (self.width + 1).saturating_sub(width);
+        let rows = (self.height + 1).saturating_sub(height);
+        (0..rows)
+            .flat_map(move |y| (0..columns).map(move |x| (x, y)))
+            .filter_map(move |(x, y)| self.window(x, y, width, height))
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        Grid::from_fn(height, self.width, |x, y| self[(y, height - 1 - x)].clone())
+    }
+
+    /// Quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        Grid::from_fn(self.height, width, |x, y| self[(width - 1 - y, x)].clone())
+    }
+
+    /// The grid and its three quarter turns clockwise.
+    pub fn rotations(&self) -> [Grid<T>; 4]
+    where
+        T: Clone,
+    {
+        let right = self.rotate_right();
+        let half = right.rotate_right();
+        let left = half.rotate_right();
+        [self.clone(), right, half, left]
+    }
+
+    /// Left and right swapped.
+    pub fn mirror_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        Grid::from_fn(width, self.height, |x, y| self[(width - 1 - x, y)].clone())
+    }
+
+    /// Top and bottom swapped.
+    pub fn mirror_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        Grid::from_fn(self.width, height, |x, y| self[(x, height - 1 - y)].clone())
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+}
+
+impl<T> GridLike for Grid<T> {
+    type Cell = T;
+
+    fn at(&self, point: Point) -> Option<&T> {
+        self.get(point.x, point.y)
+    }
+
+    fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.x, point.y)
+    }
+
+    fn points(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.iter()
+            .map(|(position, cell)| (Point::from(position), cell))
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        match self.cells.is_empty() {
+            true => None,
+            false => Some(Bounds {
+                min: Point::ORIGIN,
+                max: Point::new(self.width as isize - 1, self.height as isize - 1),
+            }),
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        Grid::parse_with(input, Ok)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "Column {x} is outside the grid");
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "Column {x} is outside the grid");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_grid() -> anyhow::Result<()> {
+        let grid = Grid::parse("abc\ndef\n")?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert!(Grid::parse("").is_err());
+        assert!(Grid::parse("abc\nde\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_diagonals() -> anyhow::Result<()> {
+        let grid = Grid::parse("abc\ndef\n")?;
+        let diagonals = grid
+            .diagonals()
+            .map(|diagonal| diagonal.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+        let anti_diagonals = grid
+            .anti_diagonals()
+            .map(|diagonal| diagonal.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(anti_diagonals, ["a", "bd", "ce", "f"]);
+        let cells = grid
+            .diagonal_major()
+            .map(|(_, cell)| cell)
+            .collect::<String>();
+        assert_eq!(cells, "daebfc");
+        Ok(())
+    }
+
+    #[test]
+    fn test_transforms() -> anyhow::Result<()> {
+        let grid = Grid::parse("abc\ndef\n")?;
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.mirror_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.mirror_vertical().to_string(), "def\nabc\n");
+        let [_, right, half, left] = grid.rotations();
+        assert_eq!(right, grid.rotate_right());
+        assert_eq!(half, grid.mirror_horizontal().mirror_vertical());
+        assert_eq!(left, grid.rotate_left());
+        assert_eq!(grid.window(1, 0, 2, 2), Some(Grid::parse("bc\nef\n")?));
+        assert_eq!(grid.window(2, 0, 2, 2), None);
+        assert_eq!(grid.windows(2, 2).count(), 2);
+        assert_eq!(grid.windows(4, 1).count(), 0);
+        let turned = grid.enumerate().rotate_right();
+        assert_eq!(turned[(0, 0)], ((0, 1), &'d'));
+        Ok(())
+    }
+}
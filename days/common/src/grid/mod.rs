@@ -0,0 +1,114 @@
+mod bits;
+mod dense;
+mod sparse;
+
+pub use bits::BitGrid;
+pub use dense::Grid;
+pub use sparse::SparseGrid;
+
+use crate::geometry::{Direction4, Direction8, Point};
+
+/// Smallest rectangle holding a set of points, both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn of(point: Point) -> Self {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Grows the bounds so they hold `point`.
+    pub fn extend(&mut self, point: Point) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> usize {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+}
+
+/// Cells addressed by [`Point`], implemented by the dense [`Grid`] and the [`SparseGrid`] so
+/// solvers can switch between them.
+pub trait GridLike {
+    type Cell;
+
+    /// Cell at `point`, nothing if there is none.
+    fn at(&self, point: Point) -> Option<&Self::Cell>;
+
+    fn at_mut(&mut self, point: Point) -> Option<&mut Self::Cell>;
+
+    /// Every cell with its position, in no particular order.
+    fn points(&self) -> impl Iterator<Item = (Point, &Self::Cell)>;
+
+    /// Rectangle holding every cell, nothing without cells.
+    fn bounds(&self) -> Option<Bounds>;
+
+    /// Orthogonal neighbours of `point` holding a cell.
+    fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> {
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| point + direction.offset())
+            .filter(|&neighbor| self.at(neighbor).is_some())
+    }
+
+    /// Orthogonal and diagonal neighbours of `point` holding a cell.
+    fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| point + direction.offset())
+            .filter(|&neighbor| self.at(neighbor).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Point,
+        grid::{Bounds, Grid, GridLike, SparseGrid},
+    };
+
+    #[test]
+    fn test_neighbors() -> anyhow::Result<()> {
+        let grid = Grid::parse("abc\ndef\n")?;
+        let corner = grid.neighbors4(Point::ORIGIN).collect::<Vec<_>>();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.at(Point::new(1, 1)), Some(&'e'));
+        assert_eq!(grid.at(Point::new(1, 2)), None);
+
+        let sparse = SparseGrid::from(&grid);
+        let mut neighbors = sparse.neighbors8(Point::new(1, 0)).collect::<Vec<_>>();
+        neighbors.sort();
+        let mut expected = grid.neighbors8(Point::new(1, 0)).collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(neighbors, expected);
+        assert_eq!(sparse.bounds(), grid.bounds());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut bounds = Bounds::of(Point::new(-2, 3));
+        bounds.extend(Point::new(1, -1));
+        assert_eq!(bounds.min, Point::new(-2, -1));
+        assert_eq!(bounds.max, Point::new(1, 3));
+        assert_eq!((bounds.width(), bounds.height()), (4, 5));
+        assert!(bounds.contains(Point::ORIGIN));
+        assert!(!bounds.contains(Point::new(2, 0)));
+    }
+}
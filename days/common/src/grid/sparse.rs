@@ -0,0 +1,149 @@
+use crate::{
+    geometry::{Point, Vec2},
+    grid::{Bounds, Grid, GridLike},
+};
+use std::collections::HashMap;
+
+/// Grid holding only the cells that were set, at any position including negative ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Sets the cell at `point`, returning the one it replaces.
+    pub fn insert(&mut self, point: Point, cell: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(point),
+            None => self.bounds = Some(Bounds::of(point)),
+        }
+        self.cells.insert(point, cell)
+    }
+
+    /// Clears the cell at `point`, shrinking the bounds if it was on their edge.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let cell = self.cells.remove(&point)?;
+        let on_edge = self.bounds.is_some_and(|bounds| {
+            [bounds.min.x, bounds.max.x].contains(&point.x)
+                || [bounds.min.y, bounds.max.y].contains(&point.y)
+        });
+        if on_edge {
+            self.bounds = self.cells.keys().fold(None, |bounds, &point| match bounds {
+                Some(mut bounds) => {
+                    bounds.extend(point);
+                    Some(bounds)
+                }
+                None => Some(Bounds::of(point)),
+            });
+        }
+        Some(cell)
+    }
+
+    /// Dense copy of the bounds, with `fill` where no cell is set. Its `(0, 0)` is the top left
+    /// corner of the bounds.
+    pub fn to_grid(&self, fill: T) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds?;
+        Some(Grid::from_fn(bounds.width(), bounds.height(), |x, y| {
+            let point = bounds.min + Vec2::new(x as isize, y as isize);
+            self.cells.get(&point).unwrap_or(&fill).clone()
+        }))
+    }
+}
+
+impl<T> GridLike for SparseGrid<T> {
+    type Cell = T;
+
+    fn at(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    fn points(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, cell)| (point, cell))
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, cell) in cells {
+            grid.insert(point, cell);
+        }
+        grid
+    }
+}
+
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        grid.points()
+            .map(|(point, cell)| (point, cell.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Point,
+        grid::{GridLike, SparseGrid},
+    };
+
+    #[test]
+    fn test_sparse_grid() -> anyhow::Result<()> {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point::new(-1, -1), '#');
+        grid.insert(Point::new(1, 0), '#');
+        grid.insert(Point::new(0, 1), '#');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.at(Point::new(1, 0)), Some(&'#'));
+        assert_eq!(grid.at(Point::ORIGIN), None);
+        let dense = grid.to_grid('.').ok_or(anyhow::anyhow!("No cells"))?;
+        assert_eq!(dense.to_string(), "#..\n..#\n.#.\n");
+
+        assert_eq!(grid.remove(Point::new(-1, -1)), Some('#'));
+        assert_eq!(grid.remove(Point::new(-1, -1)), None);
+        let bounds = grid.bounds().ok_or(anyhow::anyhow!("No cells"))?;
+        assert_eq!((bounds.min, bounds.max), (Point::ORIGIN, Point::new(1, 1)));
+        grid.remove(Point::new(1, 0));
+        grid.remove(Point::new(0, 1));
+        assert_eq!(grid.bounds(), None);
+        Ok(())
+    }
+}
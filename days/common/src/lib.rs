@@ -1,18 +1,136 @@
-use std::{fs::File, io::Read, path::PathBuf};
+#[cfg(feature = "alloc-tracking")]
+pub mod alloc;
+pub mod answers;
+pub mod crypto;
+pub mod cycle;
+pub mod embed;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod math;
+pub mod parser;
+pub mod reference;
+pub mod search;
+pub mod snapshot;
+mod solution;
+pub mod trace;
+
+pub use solution::{
+    execute, execute_part, grid_view, plausibility, run, solve_part_1, solve_part_2, GridView,
+    PartReport, Report, Solution,
+};
+
+use anyhow::bail;
+use std::{
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
 
 #[derive(Clone)]
 pub enum InputType {
     Test,
     Input(PathBuf),
+    /// Input baked in by the `embed-inputs` build, with the `.answers` file next to it if any.
+    Embedded {
+        input: &'static str,
+        answers: Option<&'static str>,
+    },
+    /// Built with `embed-inputs` while the input didn't exist, reading it fails.
+    Missing,
+}
+
+/// Example and real input of a day, see [`day_inputs`].
+#[derive(Clone)]
+pub struct DayInputs {
+    pub example: InputType,
+    pub input: InputType,
+}
+
+impl DayInputs {
+    pub fn from_dir(dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref();
+        DayInputs {
+            example: InputType::Input(dir.join("test.txt")),
+            input: InputType::Input(dir.join("input.txt")),
+        }
+    }
+}
+
+/// Inputs of the calling day crate. They are read from its directory at runtime, or embedded in
+/// the binary by its build script when the crate's `embed-inputs` feature is enabled.
+#[macro_export]
+macro_rules! day_inputs {
+    () => {{
+        #[cfg(feature = "embed-inputs")]
+        let inputs: $crate::DayInputs = include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+        #[cfg(not(feature = "embed-inputs"))]
+        let inputs = $crate::DayInputs::from_dir(env!("CARGO_MANIFEST_DIR"));
+        inputs
+    }};
 }
 
+/// Reads the input, falling back to decrypting `<path>.enc` when the plain file doesn't exist.
 pub fn get_input(input_type: InputType) -> anyhow::Result<String> {
+    get_input_with(input_type, crypto::InputKey::load)
+}
+
+/// [`get_input`] with the key of encrypted inputs from `key`, only called when one is needed.
+pub fn get_input_with(
+    input_type: InputType,
+    key: impl FnOnce() -> anyhow::Result<crypto::InputKey>,
+) -> anyhow::Result<String> {
+    let _span = tracing::info_span!("get_input").entered();
     let path = match input_type {
         InputType::Test => PathBuf::from("./test.txt"),
         InputType::Input(path) => path,
+        InputType::Embedded { input, .. } => return Ok(String::from(input)),
+        InputType::Missing => {
+            bail!("The input wasn't embedded, add input.txt or input.txt.enc and rebuild")
+        }
     };
+    if !path.exists() {
+        let encrypted = crypto::encrypted_path(&path);
+        if encrypted.exists() {
+            return crypto::decrypt_file_with(&key()?, &encrypted);
+        }
+    }
     let mut file = File::open(path)?;
     let mut file_slice = String::new();
     file.read_to_string(&mut file_slice)?;
     Ok(file_slice)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        crypto::{self, InputKey},
+        get_input_with, InputType,
+    };
+    use anyhow::anyhow;
+    use std::fs;
+
+    #[test]
+    fn test_encrypted_fallback() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let input = dir.path().join("input.txt");
+        let key = InputKey::generate();
+        fs::write(crypto::encrypted_path(&input), key.encrypt(b"3   4\n")?)?;
+        let hex = key.to_hex();
+        let key = || InputKey::from_hex(&hex);
+        assert_eq!(
+            get_input_with(InputType::Input(input.clone()), key)?,
+            "3   4\n"
+        );
+        let wrong_key = || Ok(InputKey::generate());
+        assert!(get_input_with(InputType::Input(input.clone()), wrong_key).is_err());
+
+        // The plain file wins once it exists, without asking for a key
+        fs::write(&input, "4   3\n")?;
+        let no_key = || Err(anyhow!("No key needed"));
+        assert_eq!(get_input_with(InputType::Input(input), no_key)?, "4   3\n");
+        let missing = dir.path().join("missing.txt");
+        assert!(get_input_with(InputType::Input(missing), key).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use anyhow::{anyhow, bail};
+use std::fmt::{self, Display};
+
+pub fn checked_add(a: i64, b: i64) -> anyhow::Result<i64> {
+    a.checked_add(b)
+        .ok_or_else(|| anyhow!("{a} + {b} overflows"))
+}
+
+pub fn checked_sub(a: i64, b: i64) -> anyhow::Result<i64> {
+    a.checked_sub(b)
+        .ok_or_else(|| anyhow!("{a} - {b} overflows"))
+}
+
+pub fn checked_mul(a: i64, b: i64) -> anyhow::Result<i64> {
+    a.checked_mul(b)
+        .ok_or_else(|| anyhow!("{a} * {b} overflows"))
+}
+
+pub fn checked_pow(base: i64, exponent: u32) -> anyhow::Result<i64> {
+    base.checked_pow(exponent)
+        .ok_or_else(|| anyhow!("{base} ^ {exponent} overflows"))
+}
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0, and the only gcd that doesn't fit
+/// is 2^63, of `i64::MIN` with itself or 0.
+pub fn gcd(a: i64, b: i64) -> anyhow::Result<i64> {
+    i64::try_from(wide_gcd(a.into(), b.into()))
+        .map_err(|_| anyhow!("The gcd of {a} and {b} overflows"))
+}
+
+fn wide_gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn checked_abs(value: i64) -> anyhow::Result<i64> {
+    value
+        .checked_abs()
+        .ok_or_else(|| anyhow!("|{value}| overflows"))
+}
+
+/// Least common multiple, never negative. 0 when either number is.
+pub fn lcm(a: i64, b: i64) -> anyhow::Result<i64> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+    checked_mul(checked_abs(a / gcd(a, b)?)?, checked_abs(b)?)
+}
+
+/// `(g, x, y)` with `g` the gcd of `a` and `b` and `a * x + b * y == g`. Fails when one of them
+/// doesn't fit, which takes `i64::MIN`.
+pub fn extended_gcd(a: i64, b: i64) -> anyhow::Result<(i64, i64, i64)> {
+    // Wide enough that no step overflows
+    let (mut old_r, mut r) = (i128::from(a), i128::from(b));
+    let (mut old_x, mut x) = (1_i128, 0_i128);
+    let (mut old_y, mut y) = (0_i128, 1_i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    let sign = match old_r < 0 {
+        true => -1,
+        false => 1,
+    };
+    let narrow = |value: i128| {
+        i64::try_from(sign * value).map_err(|_| anyhow!("The gcd of {a} and {b} overflows"))
+    };
+    Ok((narrow(old_r)?, narrow(old_x)?, narrow(old_y)?))
+}
+
+/// `x` in `0..modulus` with `a * x` congruent to 1, nothing when `a` and `modulus` share a
+/// factor or `modulus` isn't positive.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus).ok()?;
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Smallest `x` congruent to each residue modulo its modulus, with the lcm of the moduli it
+/// repeats with. The moduli don't need to be coprime.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> anyhow::Result<(i64, i64)> {
+    let (mut residue, mut modulus) = (0_i64, 1_i64);
+    for (other_residue, other_modulus) in congruences {
+        if other_modulus <= 0 {
+            bail!("Modulus {other_modulus} isn't positive");
+        }
+        let other_residue = other_residue.rem_euclid(other_modulus);
+        let g = gcd(modulus, other_modulus)?;
+        let difference = i128::from(other_residue) - i128::from(residue);
+        if difference % i128::from(g) != 0 {
+            bail!("x = {other_residue} mod {other_modulus} contradicts the other congruences");
+        }
+        // residue + modulus * k = other_residue (mod other_modulus), with the gcd divided out
+        let reduced = other_modulus / g;
+        let inverse = mod_inverse(modulus / g, reduced).unwrap_or(0);
+        let k = (difference / i128::from(g) * i128::from(inverse)).rem_euclid(i128::from(reduced));
+        let combined = checked_mul(modulus, reduced)?;
+        let next = (i128::from(residue) + i128::from(modulus) * k).rem_euclid(i128::from(combined));
+        residue = i64::try_from(next)?;
+        modulus = combined;
+    }
+    Ok((residue, modulus))
+}
+
+/// Exact fraction in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> anyhow::Result<Self> {
+        if denominator == 0 {
+            bail!("{numerator}/0 has no value");
+        }
+        let overflow = || anyhow!("{numerator}/{denominator} overflows");
+        let g = i128::try_from(wide_gcd(numerator, denominator)).map_err(|_| overflow())?;
+        let g = g * denominator.signum();
+        Ok(Rational {
+            numerator: numerator.checked_div(g).ok_or_else(overflow)?,
+            denominator: denominator.checked_div(g).ok_or_else(overflow)?,
+        })
+    }
+
+    pub fn integer(value: i64) -> Self {
+        Rational {
+            numerator: i128::from(value),
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// The value when it's a whole number that fits.
+    pub fn to_integer(&self) -> Option<i64> {
+        match self.is_integer() {
+            true => i64::try_from(self.numerator).ok(),
+            false => None,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.is_integer() {
+            true => write!(f, "{}", self.numerator),
+            false => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+fn overflow() -> anyhow::Error {
+    anyhow!("The determinant overflows")
+}
+
+fn determinant_2(matrix: [[i128; 2]; 2]) -> anyhow::Result<i128> {
+    let [[a, b], [c, d]] = matrix;
+    let ad = a.checked_mul(d).ok_or_else(overflow)?;
+    let bc = b.checked_mul(c).ok_or_else(overflow)?;
+    ad.checked_sub(bc).ok_or_else(overflow)
+}
+
+fn determinant_3(matrix: [[i128; 3]; 3]) -> anyhow::Result<i128> {
+    let mut determinant = 0_i128;
+    for column in 0..3 {
+        let minor = determinant_2([0, 1].map(|row| {
+            let others = [0, 1, 2].into_iter().filter(|&other| other != column);
+            let mut others = others.map(|other| matrix[row + 1][other]);
+            [others.next().unwrap_or(0), others.next().unwrap_or(0)]
+        }))?;
+        let term = matrix[0][column].checked_mul(minor).ok_or_else(overflow)?;
+        determinant = match column {
+            1 => determinant.checked_sub(term),
+            _ => determinant.checked_add(term),
+        }
+        .ok_or_else(overflow)?;
+    }
+    Ok(determinant)
+}
+
+/// Cramer's rule, each unknown is the determinant with its column replaced by `rhs` over the
+/// determinant of the system.
+fn solve<const N: usize>(
+    matrix: [[i64; N]; N],
+    rhs: [i64; N],
+    determinant: fn([[i128; N]; N]) -> anyhow::Result<i128>,
+) -> anyhow::Result<Option<[Rational; N]>> {
+    let wide = matrix.map(|row| row.map(i128::from));
+    let system = determinant(wide)?;
+    if system == 0 {
+        return Ok(None);
+    }
+    let mut solution = [Rational::integer(0); N];
+    for (column, unknown) in solution.iter_mut().enumerate() {
+        let mut replaced = wide;
+        for (row, &value) in replaced.iter_mut().zip(&rhs) {
+            row[column] = i128::from(value);
+        }
+        *unknown = Rational::new(determinant(replaced)?, system)?;
+    }
+    Ok(Some(solution))
+}
+
+/// Exact solution of `matrix * [x, y] = rhs`, nothing when the equations don't pin down a
+/// single one.
+pub fn solve_2x2(matrix: [[i64; 2]; 2], rhs: [i64; 2]) -> anyhow::Result<Option<[Rational; 2]>> {
+    solve(matrix, rhs, determinant_2)
+}
+
+/// Exact solution of `matrix * [x, y, z] = rhs`, nothing when the equations don't pin down a
+/// single one.
+pub fn solve_3x3(matrix: [[i64; 3]; 3], rhs: [i64; 3]) -> anyhow::Result<Option<[Rational; 3]>> {
+    solve(matrix, rhs, determinant_3)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{
+        checked_add, checked_mul, checked_pow, crt, extended_gcd, gcd, lcm, mod_inverse, solve_2x2,
+        solve_3x3, Rational,
+    };
+
+    #[test]
+    fn test_number_theory() -> anyhow::Result<()> {
+        assert_eq!(gcd(12, -18)?, 6);
+        assert_eq!(gcd(0, 0)?, 0);
+        assert_eq!(gcd(i64::MIN, -1)?, 1);
+        assert!(gcd(i64::MIN, 0).is_err());
+        assert_eq!(lcm(4, 6)?, 12);
+        assert!(lcm(i64::MAX, i64::MAX - 1).is_err());
+        assert!(lcm(i64::MIN, 1).is_err());
+        let (g, x, y) = extended_gcd(240, 46)?;
+        assert_eq!((g, 240 * x + 46 * y), (2, 2));
+        assert_eq!(extended_gcd(-4, 0)?, (4, -1, 0));
+        assert!(extended_gcd(i64::MIN, 0).is_err());
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_inverse(3, -11), None);
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)])?, (23, 105));
+        assert_eq!(crt([(3, 4), (5, 6)])?, (11, 12));
+        assert!(crt([(1, 4), (2, 6)]).is_err());
+        assert!(crt([(1, 0)]).is_err());
+        assert_eq!(checked_add(1, 2)?, 3);
+        assert!(checked_mul(i64::MAX, 2).is_err());
+        assert!(checked_pow(10, 19).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve() -> anyhow::Result<()> {
+        let presses = solve_2x2([[94, 22], [34, 67]], [8400, 5400])?;
+        let presses = presses.map(|solution| solution.map(|value| value.to_integer()));
+        assert_eq!(presses, Some([Some(80), Some(40)]));
+        let halves = solve_2x2([[1, 1], [1, -1]], [1, 0])?;
+        let half = Rational::new(-2, -4)?;
+        assert_eq!(halves, Some([half, half]));
+        assert_eq!(half.to_string(), "1/2");
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6])?, None);
+
+        let solution = solve_3x3([[1, 1, 1], [0, 2, 5], [2, 5, -1]], [6, -4, 27])?;
+        let solution = solution.map(|solution| solution.map(|value| value.to_integer()));
+        assert_eq!(solution, Some([Some(5), Some(3), Some(-2)]));
+        assert!(Rational::new(1, 0).is_err());
+        assert!(Rational::new(i128::MIN, -1).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::bail;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::{self, FromStr},
+};
+
+/// Ways a day can parse its input. Days compile each one only with their cargo feature of the same
+/// name, and list the compiled ones in the order below. The first one is the one they solve with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Parser combinators from `nom`
+    Nom,
+    /// Parser combinators from `chumsky`
+    Chumsky,
+    /// Lexer generated by `logos`
+    Logos,
+    /// Hand written loop over the input bytes
+    Scanner,
+}
+
+impl Backend {
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Nom => "nom",
+            Backend::Chumsky => "chumsky",
+            Backend::Logos => "logos",
+            Backend::Scanner => "scanner",
+        }
+    }
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Splits the leading ASCII digits off `bytes` and parses them, for scanner backends.
+pub fn scan_number<T>(bytes: &[u8]) -> anyhow::Result<(T, &[u8])>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    let len = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if len == 0 {
+        bail!("Expected a number");
+    }
+    let (digits, rest) = bytes.split_at(len);
+    Ok((str::from_utf8(digits)?.parse()?, rest))
+}
+
+/// Skips spaces and tabs at the start of `bytes`.
+pub fn skip_spaces(bytes: &[u8]) -> &[u8] {
+    let len = bytes
+        .iter()
+        .take_while(|&&byte| byte == b' ' || byte == b'\t')
+        .count();
+    &bytes[len..]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{scan_number, skip_spaces};
+
+    #[test]
+    fn test_scan_number() -> anyhow::Result<()> {
+        assert_eq!(scan_number::<u32>(b"123,4")?, (123, &b",4"[..]));
+        assert!(scan_number::<u32>(b",4").is_err());
+        assert!(scan_number::<u8>(b"256").is_err());
+        assert_eq!(skip_spaces(b" \t 4"), b"4");
+        Ok(())
+    }
+}
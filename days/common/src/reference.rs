@@ -0,0 +1,54 @@
+//! Differential testing of a day against its deliberately simple reference solver.
+
+use crate::Solution;
+use anyhow::bail;
+use std::fmt::Display;
+
+/// Reference solver of a part, reading the raw input.
+pub type Reference = fn(&str) -> anyhow::Result<u64>;
+
+/// Solves `input` with the day and with `references`, one per part starting with part 1. Each
+/// part must give the same answer on both sides or fail on both.
+pub fn check<S: Solution>(input: &str, references: &[Reference]) -> anyhow::Result<()> {
+    let parsed = S::parse(input)?;
+    let answers = [describe(S::part_1(&parsed)), describe(S::part_2(&parsed))];
+    for (part, (answer, reference)) in answers.into_iter().zip(references).enumerate() {
+        match (answer, describe(reference(input))) {
+            (Ok(answer), Ok(expected)) if answer == expected => {}
+            (Err(_), Err(_)) => {}
+            (answer, expected) => bail!(
+                "Part {} is {}, the reference says {}",
+                part + 1,
+                answer.unwrap_or_else(|err| err),
+                expected.unwrap_or_else(|err| err)
+            ),
+        }
+    }
+    Ok(())
+}
+
+fn describe(answer: anyhow::Result<impl Display>) -> Result<String, String> {
+    answer
+        .map(|answer| answer.to_string())
+        .map_err(|err| format!("error: {err:#}"))
+}
+
+/// Property test checking `$day` against the reference solvers of its parts on inputs from
+/// `$generate`, with sizes drawn from `$sizes`. The calling crate needs `proptest` as a
+/// dev-dependency.
+#[macro_export]
+macro_rules! reference_test {
+    ($day:ty, $generate:path, $sizes:expr, [$($reference:path),+ $(,)?]) => {
+        ::proptest::proptest! {
+            #![proptest_config(::proptest::prelude::ProptestConfig::with_cases(64))]
+
+            #[test]
+            fn test_reference(seed in ::proptest::prelude::any::<u64>(), size in $sizes) {
+                let input = $generate(seed, size);
+                $crate::reference::check::<$day>(&input, &[$($reference),+]).map_err(|err| {
+                    ::proptest::test_runner::TestCaseError::fail(format!("{err:#}"))
+                })?;
+            }
+        }
+    };
+}
@@ -0,0 +1,277 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// What a search found: the distance to each state it reached and the predecessors each one has
+/// on its shortest paths.
+#[derive(Clone, Debug)]
+pub struct Paths<S> {
+    start: S,
+    goal: Option<S>,
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    fn new(start: S) -> Self {
+        Paths {
+            distances: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            goal: None,
+            start,
+        }
+    }
+
+    /// Records that `state` is reached in `distance` through `from`, returning whether it's a
+    /// new shortest distance.
+    fn reach(&mut self, state: S, distance: u64, from: &S) -> bool {
+        match self.distances.get(&state) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                self.predecessors
+                    .entry(state)
+                    .or_default()
+                    .push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(state.clone(), distance);
+                self.predecessors.insert(state, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// First goal state reached, nothing if the search exhausted every state without one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, u64> {
+        &self.distances
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// States right before `state` on its shortest paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// A shortest path from the start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// A shortest path from the start to the goal.
+    pub fn best_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Every shortest path from the start to `state`. There can be exponentially many, prefer
+    /// [`Paths::states_on_paths`] when only the states matter.
+    pub fn all_paths(&self, state: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return vec![];
+        }
+        let predecessors = self.predecessors(state);
+        if predecessors.is_empty() {
+            return vec![vec![state.clone()]];
+        }
+        let mut paths = vec![];
+        for previous in predecessors {
+            for mut path in self.all_paths(previous) {
+                path.push(state.clone());
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// States on any shortest path from the start to `state`.
+    pub fn states_on_paths(&self, state: &S) -> HashSet<S> {
+        let mut states = HashSet::new();
+        if !self.distances.contains_key(state) {
+            return states;
+        }
+        let mut pending = vec![state.clone()];
+        while let Some(state) = pending.pop() {
+            if states.insert(state.clone()) {
+                pending.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        states
+    }
+}
+
+/// Breadth-first search from `start`, every step costs 1. Stops at the first state `is_goal`
+/// holds for, pass `|_| false` to reach every state.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, distance)) = queue.pop_front() {
+        // Every state one step closer was expanded already, so the goal has all its predecessors
+        if is_goal(&state) {
+            paths.goal = Some(state);
+            break;
+        }
+        for next in neighbours(&state) {
+            if paths.reach(next.clone(), distance + 1, &state) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's search from `start` with the cost of each step, which must be at least 1. Stops
+/// once the first goal and all its shortest paths are found, pass `|_| false` to reach every
+/// state.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// A* search from `start` with the cost of each step, which must be at least 1: free steps would
+/// let states be each other's predecessors and paths go round forever, so they panic.
+/// `heuristic` must never overestimate the cost left to a goal, and never drop by more than a
+/// step costs for [`Paths::all_paths`] to hold every shortest path.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut paths = Paths::new(start.clone());
+    // States are kept aside so they don't have to be `Ord` to sit in the heap
+    let mut states = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&states[0]), 0, 0))]);
+    let mut goal_distance = None;
+
+    while let Some(Reverse((estimate, distance, index))) = heap.pop() {
+        if goal_distance.is_some_and(|goal_distance| estimate > goal_distance) {
+            break;
+        }
+        let state = states[index].clone();
+        if paths.distances[&state] < distance {
+            continue;
+        }
+        if is_goal(&state) {
+            if paths.goal.is_none() {
+                goal_distance = Some(distance);
+                paths.goal = Some(state);
+            }
+            continue;
+        }
+        for (next, cost) in neighbours(&state) {
+            assert!(cost > 0, "Steps must cost at least 1");
+            let next_distance = distance + cost;
+            if paths.reach(next.clone(), next_distance, &state) {
+                let estimate = next_distance + heuristic(&next);
+                heap.push(Reverse((estimate, next_distance, states.len())));
+                states.push(next);
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Point,
+        grid::{Grid, GridLike},
+        search::{astar, bfs, dijkstra},
+    };
+
+    #[test]
+    fn test_bfs() -> anyhow::Result<()> {
+        let maze = Grid::parse("...\n.#.\n...\n")?;
+        let goal = Point::new(2, 2);
+        let paths = bfs(
+            Point::ORIGIN,
+            |&point| {
+                maze.neighbors4(point)
+                    .filter(|&next| maze.at(next) == Some(&'.'))
+                    .collect::<Vec<_>>()
+            },
+            |&point| point == goal,
+        );
+        assert_eq!(paths.goal(), Some(&goal));
+        assert_eq!(paths.distance(&goal), Some(4));
+        assert_eq!(paths.best_path().map(|path| path.len()), Some(5));
+        assert_eq!(paths.all_paths(&goal).len(), 2);
+        assert_eq!(paths.states_on_paths(&goal).len(), 8);
+
+        let everything = bfs(Point::ORIGIN, |&point| maze.neighbors4(point), |_| false);
+        assert_eq!(everything.goal(), None);
+        assert_eq!(everything.distances().len(), 9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Two ways from 0 to 3 costing 5 and a shorter looking one costing 6
+        let edges = |&state: &u32| match state {
+            0 => vec![(1, 2), (2, 3), (3, 6)],
+            1 => vec![(3, 3)],
+            2 => vec![(3, 2)],
+            _ => vec![],
+        };
+        let paths = dijkstra(0, edges, |&state| state == 3);
+        assert_eq!(paths.distance(&3), Some(5));
+        let mut all = paths.all_paths(&3);
+        all.sort();
+        assert_eq!(all, [vec![0, 1, 3], vec![0, 2, 3]]);
+        assert!(paths.predecessors(&0).is_empty());
+
+        let guided = astar(
+            0,
+            edges,
+            |&state| u64::from(state != 3),
+            |&state| state == 3,
+        );
+        assert_eq!(guided.distance(&3), Some(5));
+        assert_eq!(guided.all_paths(&3).len(), 2);
+        assert_eq!(dijkstra(0, edges, |&state| state == 9).goal(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Steps must cost at least 1")]
+    fn test_free_step() {
+        dijkstra(0, |&state: &u32| [(1 - state, 0)], |_| false);
+    }
+}
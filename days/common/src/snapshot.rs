@@ -0,0 +1,92 @@
+use anyhow::{bail, Context};
+use std::{env, fs, path::Path};
+
+/// When set, snapshot assertions write what they got instead of comparing.
+pub const ACCEPT_ENV: &str = "AOC_SNAPSHOT_ACCEPT";
+
+/// Compares `actual` with the snapshot stored at `path`, failing with a line diff when they
+/// differ. With [`ACCEPT_ENV`] set the snapshot is (re)written instead.
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    if env::var_os(ACCEPT_ENV).is_some() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, actual)
+            .with_context(|| format!("Couldn't write snapshot {}", path.display()))?;
+        return Ok(());
+    }
+
+    let Ok(expected) = fs::read_to_string(path) else {
+        bail!(
+            "No snapshot at {}, review and accept it with `aoc snapshot --accept`",
+            path.display()
+        );
+    };
+    if expected != actual {
+        bail!(
+            "Snapshot {} changed, accept it with `aoc snapshot --accept` if that's intended\n{}",
+            path.display(),
+            diff(&expected, actual)
+        );
+    }
+    Ok(())
+}
+
+/// Compares the parsed structure of a day with `snapshots/<name>.snap` in the calling crate.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:literal, $actual:expr) => {
+        $crate::snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots/", $name, ".snap"),
+            &$actual,
+        )
+    };
+}
+
+/// Line diff from the longest common subsequence, removed lines start with `-` and added ones
+/// with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    // common[i][j] is the LCS length of expected[i..] and actual[j..]
+    let mut common = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = match expected[i] == actual[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut output = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            output.push_str(&format!("  {}\n", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || (i < expected.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            output.push_str(&format!("- {}\n", expected[i]));
+            i += 1;
+        } else {
+            output.push_str(&format!("+ {}\n", actual[j]));
+            j += 1;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::snapshot::diff;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("a\nb\nc\n", "a\nc\nd\n"), "  a\n- b\n  c\n+ d\n");
+        assert_eq!(diff("", "a\n"), "+ a\n");
+    }
+}
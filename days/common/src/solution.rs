@@ -0,0 +1,160 @@
+use crate::{get_input, trace, InputType};
+use anyhow::bail;
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// A day's puzzle, split into parsing and the two parts so each phase can be measured on its own.
+pub trait Solution {
+    const DAY: u8;
+    type Parsed;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed>;
+    fn part_1(parsed: &Self::Parsed) -> anyhow::Result<Self::Part1>;
+    fn part_2(parsed: &Self::Parsed) -> anyhow::Result<Self::Part2>;
+
+    /// How well `input` fits this day once it parsed, from 0 to 1. Parsers that accept almost
+    /// anything should look at the parsed structure to tell their inputs apart.
+    fn plausibility(_input: &str, _parsed: &Self::Parsed) -> f64 {
+        1.0
+    }
+
+    /// Grid based days return their grid with the cells making up each part's answer.
+    fn grid_view(_parsed: &Self::Parsed) -> Option<GridView> {
+        None
+    }
+}
+
+/// Character grid with the `(x, y)` cells counted by each part highlighted.
+#[derive(Clone, Debug, Default)]
+pub struct GridView {
+    pub rows: Vec<String>,
+    pub part_1: Vec<(usize, usize)>,
+    pub part_2: Vec<(usize, usize)>,
+}
+
+/// Plausibility of `input` for this day, failing when it doesn't parse.
+pub fn plausibility<S: Solution>(input: &str) -> anyhow::Result<f64> {
+    Ok(S::plausibility(input, &S::parse(input)?).clamp(0.0, 1.0))
+}
+
+pub fn grid_view<S: Solution>(input: &str) -> anyhow::Result<Option<GridView>> {
+    Ok(S::grid_view(&S::parse(input)?))
+}
+
+pub fn solve_part_1<S: Solution>(input_type: InputType) -> anyhow::Result<S::Part1> {
+    let input = get_input(input_type)?;
+    S::part_1(&S::parse(&input)?)
+}
+
+pub fn solve_part_2<S: Solution>(input_type: InputType) -> anyhow::Result<S::Part2> {
+    let input = get_input(input_type)?;
+    S::part_2(&S::parse(&input)?)
+}
+
+pub struct PartReport {
+    pub answer: anyhow::Result<String>,
+    pub time: Duration,
+}
+
+/// Answers and timings of a single run, a failing part doesn't prevent the other from running.
+pub struct Report {
+    pub parse_time: Duration,
+    pub part_1: PartReport,
+    pub part_2: PartReport,
+}
+
+/// Parses `input` once and solves both parts, timing each phase.
+pub fn execute<S: Solution>(input: &str) -> anyhow::Result<Report> {
+    let _span = tracing::info_span!("solve", day = S::DAY).entered();
+
+    let (parsed, parse_time) = parse_timed::<S>(input)?;
+    Ok(Report {
+        parse_time,
+        part_1: part_1_timed::<S>(&parsed),
+        part_2: part_2_timed::<S>(&parsed),
+    })
+}
+
+/// Parses `input` and solves only `part`, returning the parse time along with the part.
+pub fn execute_part<S: Solution>(input: &str, part: u8) -> anyhow::Result<(Duration, PartReport)> {
+    let _span = tracing::info_span!("solve", day = S::DAY, part).entered();
+
+    if !matches!(part, 1 | 2) {
+        bail!("Part {part} doesn't exist");
+    }
+    let (parsed, parse_time) = parse_timed::<S>(input)?;
+    let report = match part {
+        1 => part_1_timed::<S>(&parsed),
+        _ => part_2_timed::<S>(&parsed),
+    };
+    Ok((parse_time, report))
+}
+
+fn parse_timed<S: Solution>(input: &str) -> anyhow::Result<(S::Parsed, Duration)> {
+    let start = Instant::now();
+    let parsed = phase("Parse", || {
+        tracing::info_span!("parse").in_scope(|| S::parse(input))
+    })?;
+    Ok((parsed, start.elapsed()))
+}
+
+fn part_1_timed<S: Solution>(parsed: &S::Parsed) -> PartReport {
+    let start = Instant::now();
+    let answer = phase("Part 1", || {
+        tracing::info_span!("part_1").in_scope(|| S::part_1(parsed))
+    });
+    PartReport {
+        answer: answer.map(|answer| answer.to_string()),
+        time: start.elapsed(),
+    }
+}
+
+fn part_2_timed<S: Solution>(parsed: &S::Parsed) -> PartReport {
+    let start = Instant::now();
+    let answer = phase("Part 2", || {
+        tracing::info_span!("part_2").in_scope(|| S::part_2(parsed))
+    });
+    PartReport {
+        answer: answer.map(|answer| answer.to_string()),
+        time: start.elapsed(),
+    }
+}
+
+/// Entry point of the day binaries: parses the input once and prints both parts. A part that
+/// fails prints its error in place of the answer, only a failed parse fails the run.
+///
+/// Passing `--trace <file>` to the binary writes a Chrome trace of the run to `<file>`.
+pub fn run<S: Solution>(input_type: InputType) -> anyhow::Result<()> {
+    let _guard = trace::trace_path_from_args()
+        .map(trace::init_chrome_trace)
+        .transpose()?;
+
+    let input = get_input(input_type)?;
+    let report = execute::<S>(&input)?;
+    print_part("Part 1", &report.part_1);
+    print_part("Part 2", &report.part_2);
+    Ok(())
+}
+
+fn print_part(label: &str, report: &PartReport) {
+    match &report.answer {
+        Ok(answer) => println!("{label}: {answer}"),
+        Err(err) => println!("{label}: error: {err:#}"),
+    }
+}
+
+#[cfg(feature = "alloc-tracking")]
+fn phase<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    let (result, stats) = crate::alloc::measure(f);
+    eprintln!("[alloc] {name}: {stats}");
+    result
+}
+
+#[cfg(not(feature = "alloc-tracking"))]
+fn phase<T>(_name: &str, f: impl FnOnce() -> T) -> T {
+    f()
+}
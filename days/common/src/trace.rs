@@ -0,0 +1,34 @@
+//! Chrome trace export of the `tracing` spans, viewable in `chrome://tracing` or Perfetto.
+
+use anyhow::Context;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::prelude::*;
+
+/// Installs a global subscriber writing every span to `path`, failing if one is already set. The
+/// trace is flushed when the returned guard is dropped.
+pub fn init_chrome_trace(path: impl AsRef<Path>) -> anyhow::Result<FlushGuard> {
+    let (layer, guard) = ChromeLayerBuilder::new()
+        .file(path)
+        .include_args(true)
+        .build();
+    tracing_subscriber::registry()
+        .with(layer)
+        .try_init()
+        .context("A tracing subscriber is already installed")?;
+    Ok(guard)
+}
+
+/// Looks for `--trace <file>` in the process arguments.
+pub fn trace_path_from_args() -> Option<PathBuf> {
+    let mut args = env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--trace" {
+            return args.next().map(PathBuf::from);
+        }
+    }
+    None
+}
@@ -0,0 +1,37 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` lines of five digit location ID pairs. About a third of the right IDs are copied from
+/// the left list so part 2 has similarities to score.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+    let left = (0..size)
+        .map(|_| rng.gen_range(10000..100000))
+        .collect::<Vec<u32>>();
+
+    let mut output = String::new();
+    for &id in &left {
+        let other = match rng.gen_bool(0.3) {
+            true => left[rng.gen_range(0..size)],
+            false => rng.gen_range(10000..100000),
+        };
+        output.push_str(&format!("{id}   {other}\n"));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, Day1};
+    use common::Solution;
+
+    #[test]
+    fn test_generate() -> anyhow::Result<()> {
+        assert_eq!(generate(7, 100), generate(7, 100));
+        assert_ne!(generate(7, 100), generate(8, 100));
+        let parsed = Day1::parse(&generate(7, 100))?;
+        assert_eq!(parsed.len(), 100);
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+pub mod generate;
+pub mod parsers;
+pub mod reference;
+
+use common::{DayInputs, Solution};
+
+pub struct Day1;
+
+pub fn inputs() -> DayInputs {
+    common::day_inputs!()
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Parsed = Vec<(i32, i32)>;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        parsers::parse_with(parsers::BACKENDS[0], input)
+    }
+
+    fn part_1(tokens: &Self::Parsed) -> anyhow::Result<u64> {
+        let (mut left_tokens, mut right_tokens): (Vec<_>, Vec<_>) = tokens.iter().copied().unzip();
+
+        left_tokens.sort_unstable_by(Ord::cmp);
+        right_tokens.sort_unstable_by(Ord::cmp);
+
+        let result: u64 = left_tokens
+            .into_iter()
+            .zip(right_tokens)
+            .map(|(left, right)| u64::from(left.abs_diff(right)))
+            .sum();
+
+        Ok(result)
+    }
+
+    fn part_2(tokens: &Self::Parsed) -> anyhow::Result<usize> {
+        let (left_tokens, right_tokens): (Vec<_>, Vec<_>) = tokens.iter().copied().unzip();
+
+        let result: usize = left_tokens
+            .into_iter()
+            .map(|token| {
+                let count = right_tokens
+                    .iter()
+                    .filter(|&&other_token| other_token == token)
+                    .count();
+                count * token as usize
+            })
+            .sum();
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day1;
+    use common::{assert_snapshot, get_input, solve_part_1, solve_part_2, InputType, Solution};
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let res = solve_part_1::<Day1>(InputType::Test);
+        println!("{}", res?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let res = solve_part_2::<Day1>(InputType::Test);
+        println!("{}", res?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() -> anyhow::Result<()> {
+        let tokens = Day1::parse(&get_input(InputType::Test)?)?;
+        let snapshot = tokens
+            .iter()
+            .map(|pair| format!("{pair:?}\n"))
+            .collect::<String>();
+        assert_snapshot!("example", snapshot)
+    }
+}
@@ -0,0 +1,21 @@
+use anyhow::anyhow;
+use chumsky::{error::Rich, extra, prelude::*, text};
+
+fn parser<'a>() -> impl Parser<'a, &'a str, Vec<(i32, i32)>, extra::Err<Rich<'a, char>>> {
+    let id = text::digits(10)
+        .to_slice()
+        .try_map(|digits: &str, span| digits.parse::<i32>().map_err(|err| Rich::custom(span, err)));
+    let pair = id
+        .then_ignore(text::inline_whitespace())
+        .then(id)
+        .then_ignore(just('\n'));
+
+    pair.repeated().at_least(1).collect().then_ignore(end())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<(i32, i32)>> {
+    parser()
+        .parse(input)
+        .into_result()
+        .map_err(|errors| anyhow!("Parsing errors: {errors:?}"))
+}
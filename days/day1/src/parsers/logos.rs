@@ -0,0 +1,33 @@
+use anyhow::{anyhow, bail};
+use logos::Logos;
+
+#[derive(Debug, Logos, PartialEq)]
+#[logos(skip r"[ \t]+")]
+enum Token {
+    #[regex("[0-9]+", |lex| lex.slice().parse::<i32>().ok())]
+    Id(i32),
+    #[token("\n")]
+    NewLine,
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<(i32, i32)>> {
+    let tokens = tracing::info_span!("lex").in_scope(|| {
+        Token::lexer(input)
+            .spanned()
+            .map(|(token, span)| token.map_err(|_| anyhow!("Unexpected input at {span:?}")))
+            .collect::<anyhow::Result<Vec<_>>>()
+    })?;
+
+    let _span = tracing::info_span!("parse").entered();
+    let mut pairs = Vec::with_capacity(tokens.len() / 3);
+    for line in tokens.chunks(3) {
+        match line {
+            [Token::Id(left), Token::Id(right), Token::NewLine] => pairs.push((*left, *right)),
+            _ => bail!("Expected two IDs and a new line, found {line:?}"),
+        }
+    }
+    if pairs.is_empty() {
+        bail!("No pairs found");
+    }
+    Ok(pairs)
+}
@@ -0,0 +1,30 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, space0},
+    combinator::{all_consuming, map_res},
+    multi::many1,
+    sequence::{terminated, tuple},
+    IResult,
+};
+
+fn pair(input: &str) -> IResult<&str, (i32, i32)> {
+    let (input, (left, _, right)) = tuple((
+        map_res(digit1, |a: &str| a.parse::<i32>()),
+        space0,
+        map_res(digit1, |a: &str| a.parse::<i32>()),
+    ))(input)?;
+    Ok((input, (left, right)))
+}
+
+fn line(input: &str) -> IResult<&str, (i32, i32)> {
+    terminated(pair, tag("\n"))(input)
+}
+
+fn tokens(input: &str) -> IResult<&str, Vec<(i32, i32)>> {
+    all_consuming(many1(line))(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<(i32, i32)>> {
+    let (_, tokens) = tokens(input).map_err(|err| err.to_owned())?;
+    Ok(tokens)
+}
@@ -0,0 +1,20 @@
+use anyhow::bail;
+use common::parser::{scan_number, skip_spaces};
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<(i32, i32)>> {
+    let mut bytes = input.as_bytes();
+    let mut pairs = vec![];
+    while !bytes.is_empty() {
+        let (left, rest) = scan_number(bytes)?;
+        let (right, rest) = scan_number(skip_spaces(rest))?;
+        let Some((b'\n', rest)) = rest.split_first() else {
+            bail!("Expected a new line after each pair");
+        };
+        pairs.push((left, right));
+        bytes = rest;
+    }
+    if pairs.is_empty() {
+        bail!("No pairs found");
+    }
+    Ok(pairs)
+}
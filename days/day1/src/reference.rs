@@ -0,0 +1,48 @@
+//! Deliberately simple solutions to check the real ones against, reading the raw input.
+
+fn lists(input: &str) -> anyhow::Result<(Vec<i64>, Vec<i64>)> {
+    let mut left = vec![];
+    let mut right = vec![];
+    for line in input.lines() {
+        let ids = line.split_whitespace().collect::<Vec<_>>();
+        left.push(ids[0].parse()?);
+        right.push(ids[1].parse()?);
+    }
+    Ok((left, right))
+}
+
+pub fn part_1(input: &str) -> anyhow::Result<u64> {
+    let (mut left, mut right) = lists(input)?;
+    left.sort();
+    right.sort();
+    let mut total = 0;
+    for index in 0..left.len() {
+        total += left[index].abs_diff(right[index]);
+    }
+    Ok(total)
+}
+
+pub fn part_2(input: &str) -> anyhow::Result<u64> {
+    let (left, right) = lists(input)?;
+    let mut total = 0;
+    for id in &left {
+        for other in &right {
+            if id == other {
+                total += *id as u64;
+            }
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, reference, Day1};
+
+    common::reference_test!(
+        Day1,
+        generate,
+        1..300usize,
+        [reference::part_1, reference::part_2]
+    );
+}
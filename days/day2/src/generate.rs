@@ -0,0 +1,58 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` reports of five to eight levels. About half of them break the rules once, with a
+/// repeated level, a jump of more than three or a change of direction.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let len = rng.gen_range(5..=8);
+        let direction = match rng.gen_bool(0.5) {
+            true => 1,
+            false => -1,
+        };
+        // Far enough from zero that no step or broken level can go negative
+        let mut levels = vec![rng.gen_range(35..65)];
+        for _ in 1..len {
+            let step = rng.gen_range(1..=3);
+            levels.push(levels[levels.len() - 1] + direction * step);
+        }
+
+        if rng.gen_bool(0.5) {
+            let index = rng.gen_range(1..len);
+            let previous = levels[index - 1];
+            levels[index] = match rng.gen_range(0..3) {
+                0 => previous,
+                1 => previous + direction * rng.gen_range(4..=7),
+                _ => previous - direction * rng.gen_range(1..=3),
+            };
+        }
+
+        let report = levels
+            .iter()
+            .map(i32::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        output.push_str(&report);
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, Day2};
+    use common::Solution;
+
+    #[test]
+    fn test_generate() -> anyhow::Result<()> {
+        assert_eq!(generate(7, 100), generate(7, 100));
+        assert_ne!(generate(7, 100), generate(8, 100));
+        let parsed = Day2::parse(&generate(7, 100))?;
+        assert_eq!(parsed.len(), 100);
+        assert!(parsed.iter().all(|report| (5..=8).contains(&report.len())));
+        Ok(())
+    }
+}
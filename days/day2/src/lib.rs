@@ -0,0 +1,141 @@
+pub mod generate;
+pub mod parsers;
+pub mod reference;
+
+use anyhow::bail;
+use common::{DayInputs, Solution};
+use std::cmp::{Ordering, PartialEq};
+
+#[derive(Debug, PartialEq)]
+pub enum LevelSlope {
+    Increasing,
+    Decreasing,
+}
+
+pub struct State {
+    previous: Option<u32>,
+    slope: Option<LevelSlope>,
+}
+
+pub struct Day2;
+
+pub fn inputs() -> DayInputs {
+    common::day_inputs!()
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Parsed = Vec<Vec<u32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        parsers::parse_with(parsers::BACKENDS[0], input)
+    }
+
+    fn part_1(lines: &Self::Parsed) -> anyhow::Result<usize> {
+        let res = lines
+            .iter()
+            .filter(|line| {
+                let length = line.len();
+
+                let res = line
+                    .iter()
+                    .scan(
+                        State {
+                            previous: None,
+                            slope: None,
+                        },
+                        |state, entry| {
+                            match state.previous {
+                                // First iteration
+                                None => {
+                                    state.previous = Some(*entry);
+                                    Some(())
+                                }
+                                Some(previous) => {
+                                    state.previous = Some(*entry);
+                                    let entry = *entry;
+
+                                    match &state.slope {
+                                        // Second iteration
+                                        None => match previous.cmp(&entry) {
+                                            Ordering::Less => {
+                                                state.slope = Some(LevelSlope::Increasing)
+                                            }
+                                            Ordering::Greater => {
+                                                state.slope = Some(LevelSlope::Decreasing)
+                                            }
+                                            Ordering::Equal => {
+                                                return None;
+                                            }
+                                        },
+
+                                        // All other iterations
+                                        Some(slope) => match previous.cmp(&entry) {
+                                            Ordering::Less => {
+                                                if *slope != LevelSlope::Increasing {
+                                                    return None;
+                                                }
+                                            }
+                                            Ordering::Greater => {
+                                                if *slope != LevelSlope::Decreasing {
+                                                    return None;
+                                                }
+                                            }
+                                            Ordering::Equal => {
+                                                return None;
+                                            }
+                                        },
+                                    }
+                                    let distance = previous.abs_diff(entry);
+                                    match distance > 0 && distance <= 3 {
+                                        true => Some(()),
+                                        false => None,
+                                    }
+                                }
+                            }
+                        },
+                    )
+                    .count();
+                length == res
+            })
+            .count();
+
+        Ok(res)
+    }
+
+    fn part_2(_lines: &Self::Parsed) -> anyhow::Result<usize> {
+        bail!("Part 2 isn't solved yet")
+    }
+
+    /// Share of the five levels every report has that are actually there, so lists of pairs,
+    /// which parse too but belong to day 1, score low.
+    fn plausibility(_input: &str, lines: &Self::Parsed) -> f64 {
+        let levels = lines.iter().map(|line| line.len().min(5)).sum::<usize>();
+        levels as f64 / (5 * lines.len()).max(1) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day2;
+    use common::{assert_snapshot, get_input, solve_part_1, InputType, Solution};
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let res = solve_part_1::<Day2>(InputType::Test)?;
+        println!("{res}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() -> anyhow::Result<()> {
+        let lines = Day2::parse(&get_input(InputType::Test)?)?;
+        let snapshot = lines
+            .iter()
+            .map(|report| format!("{report:?}\n"))
+            .collect::<String>();
+        assert_snapshot!("example", snapshot)
+    }
+}
@@ -0,0 +1,23 @@
+use anyhow::anyhow;
+use chumsky::{error::Rich, extra, prelude::*, text};
+
+fn parser<'a>() -> impl Parser<'a, &'a str, Vec<Vec<u32>>, extra::Err<Rich<'a, char>>> {
+    let level = text::digits(10)
+        .to_slice()
+        .try_map(|digits: &str, span| digits.parse::<u32>().map_err(|err| Rich::custom(span, err)));
+    let report = level
+        .then_ignore(text::inline_whitespace())
+        .repeated()
+        .at_least(1)
+        .collect()
+        .then_ignore(just('\n'));
+
+    report.repeated().at_least(1).collect().then_ignore(end())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<u32>>> {
+    parser()
+        .parse(input)
+        .into_result()
+        .map_err(|errors| anyhow!("Parsing errors: {errors:?}"))
+}
@@ -0,0 +1,42 @@
+use anyhow::{anyhow, bail};
+use logos::Logos;
+use std::mem;
+
+#[derive(Debug, Logos, PartialEq)]
+#[logos(skip r"[ \t]+")]
+enum Token {
+    #[regex("[0-9]+", |lex| lex.slice().parse::<u32>().ok())]
+    Level(u32),
+    #[token("\n")]
+    NewLine,
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<u32>>> {
+    let tokens = tracing::info_span!("lex").in_scope(|| {
+        Token::lexer(input)
+            .spanned()
+            .map(|(token, span)| {
+                let token = token.map_err(|_| anyhow!("Unexpected input at {span:?}"))?;
+                Ok((token, span))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+    })?;
+
+    let _span = tracing::info_span!("parse").entered();
+    let mut reports = vec![];
+    let mut report = vec![];
+    for (token, span) in tokens {
+        match token {
+            Token::Level(level) => report.push(level),
+            Token::NewLine if report.is_empty() => bail!("Empty report at {span:?}"),
+            Token::NewLine => reports.push(mem::take(&mut report)),
+        }
+    }
+    if !report.is_empty() {
+        bail!("Expected a new line after the last report");
+    }
+    if reports.is_empty() {
+        bail!("No reports found");
+    }
+    Ok(reports)
+}
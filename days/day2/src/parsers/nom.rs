@@ -0,0 +1,27 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, space0},
+    combinator::all_consuming,
+    error::ErrorKind,
+    multi::many1,
+    sequence::terminated,
+    IResult,
+};
+
+fn line(input: &str) -> IResult<&str, Vec<u32>> {
+    let (input, sequence) = terminated(many1(terminated(digit1, space0)), tag("\n"))(input)?;
+    let mut new_sequence = Vec::with_capacity(sequence.len());
+    for entry in sequence {
+        let num = entry
+            .parse::<u32>()
+            .map_err(|_| nom::Err::Failure(nom::error::Error::new(input, ErrorKind::Digit)))?;
+        new_sequence.push(num);
+    }
+
+    Ok((input, new_sequence))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<u32>>> {
+    let (_, lines) = all_consuming(many1(line))(input).map_err(|err| err.to_owned())?;
+    Ok(lines)
+}
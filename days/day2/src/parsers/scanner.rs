@@ -0,0 +1,24 @@
+use anyhow::bail;
+use common::parser::{scan_number, skip_spaces};
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<u32>>> {
+    let mut bytes = input.as_bytes();
+    let mut reports = vec![];
+    while !bytes.is_empty() {
+        let mut report = vec![];
+        loop {
+            let (level, rest) = scan_number(bytes)?;
+            report.push(level);
+            bytes = skip_spaces(rest);
+            if let Some((b'\n', rest)) = bytes.split_first() {
+                bytes = rest;
+                break;
+            }
+        }
+        reports.push(report);
+    }
+    if reports.is_empty() {
+        bail!("No reports found");
+    }
+    Ok(reports)
+}
@@ -0,0 +1,33 @@
+//! Deliberately simple solution to check the real one against, reading the raw input.
+
+fn is_safe(levels: &[i64]) -> bool {
+    let increasing = levels
+        .windows(2)
+        .all(|pair| (1..=3).contains(&(pair[1] - pair[0])));
+    let decreasing = levels
+        .windows(2)
+        .all(|pair| (1..=3).contains(&(pair[0] - pair[1])));
+    increasing || decreasing
+}
+
+pub fn part_1(input: &str) -> anyhow::Result<u64> {
+    let mut safe = 0;
+    for line in input.lines() {
+        let levels = line
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<i64>, _>>()?;
+        if is_safe(&levels) {
+            safe += 1;
+        }
+    }
+    Ok(safe)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, reference, Day2};
+
+    // Part 2 isn't solved yet, Day2::part_2 always fails, so there is nothing to check it against
+    common::reference_test!(Day2, generate, 1..300usize, [reference::part_1]);
+}
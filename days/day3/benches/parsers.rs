@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day3::{
+    generate::generate,
+    parsers::{parse_with, BACKENDS},
+};
+
+fn parsers(c: &mut Criterion) {
+    let input = generate(0, 10_000);
+    let mut group = c.benchmark_group("day3 parse");
+    for &backend in BACKENDS {
+        group.bench_with_input(BenchmarkId::from_parameter(backend), &input, |b, input| {
+            b.iter(|| parse_with(backend, input))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parsers);
+criterion_main!(benches);
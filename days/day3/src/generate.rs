@@ -0,0 +1,90 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Near misses and noise put between instructions, all of them must stay garbage.
+const GARBAGE: &[&str] = &[
+    "mul(4*",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "mul(1000,2)",
+    "mul(6,9!",
+    "?(12,34)",
+    "do(",
+    "don't",
+    "do_not()",
+    "what()",
+    "select()",
+    "from()",
+    "how()",
+    "when()",
+    "where(536,162)",
+    "#",
+    "~",
+    "^",
+    "'",
+    "[",
+    "]",
+    "{",
+    "}",
+    "<",
+    ">",
+    "%",
+    "$",
+    "@",
+    "!",
+    "&",
+    "+",
+    ",",
+    " ",
+    "-",
+    "/",
+    "*",
+    ":",
+    ";",
+];
+
+/// `size` instructions of corrupted memory, mostly `mul(a,b)` with a sprinkling of `do()` and
+/// `don't()`, separated by garbage and split over lines like the real input.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let mut output = String::new();
+    for index in 0..size.max(1) {
+        for _ in 0..rng.gen_range(0..4) {
+            output.push_str(GARBAGE.choose(&mut rng).unwrap());
+        }
+        match rng.gen_range(0..10) {
+            0 => output.push_str("do()"),
+            1 => output.push_str("don't()"),
+            _ => output.push_str(&format!(
+                "mul({},{})",
+                rng.gen_range(1..1000),
+                rng.gen_range(1..1000)
+            )),
+        }
+        if index % 30 == 29 {
+            output.push('\n');
+        }
+    }
+    output.push('\n');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, lexer::Token, Day3};
+    use common::Solution;
+
+    #[test]
+    fn test_generate() -> anyhow::Result<()> {
+        assert_eq!(generate(7, 100), generate(7, 100));
+        assert_ne!(generate(7, 100), generate(8, 100));
+        let parsed = Day3::parse(&generate(7, 100))?;
+        let instructions = parsed
+            .iter()
+            .filter(|token| matches!(token, Token::Mul(_) | Token::Do | Token::Dont))
+            .count();
+        assert_eq!(instructions, 100);
+        Ok(())
+    }
+}
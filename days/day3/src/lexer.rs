@@ -1,5 +1,6 @@
+use anyhow::anyhow;
 use logos::{Lexer, Logos};
-use std::num::ParseIntError;
+use std::{num::ParseIntError, ops::Range};
 
 #[derive(Clone, Debug, Logos, PartialEq)]
 #[logos(error = CustomError)]
@@ -18,6 +19,30 @@ pub enum Token {
     Dont,
 }
 
+impl Token {
+    #[cfg(feature = "debug")]
+    pub fn garbage(character: char) -> Self {
+        Token::Garbage(String::from(character))
+    }
+
+    #[cfg(not(feature = "debug"))]
+    pub fn garbage(_character: char) -> Self {
+        Token::Garbage
+    }
+
+    /// Single character that isn't part of an instruction.
+    pub fn is_garbage(&self) -> bool {
+        #[cfg(feature = "debug")]
+        {
+            matches!(self, Token::Garbage(_))
+        }
+        #[cfg(not(feature = "debug"))]
+        {
+            matches!(self, Token::Garbage)
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct CustomError {
     message: String,
@@ -39,3 +64,27 @@ fn mul(lex: &mut Lexer<Token>) -> Result<(u32, u32), ParseIntError> {
     let num2 = pair[1].parse()?;
     Ok((num1, num2))
 }
+
+/// Lexes `input` into tokens and their spans. Logos gives up on a partial `do()` or `don't()`
+/// with one garbage token covering all of it, which is split back into single characters.
+pub fn lex(input: &str) -> anyhow::Result<Vec<(Token, Range<usize>)>> {
+    let mut tokens = vec![];
+    for (token, span) in Token::lexer(input).spanned() {
+        let token = token.map_err(|err| anyhow!("Lexing failed at {span:?}: {err:?}"))?;
+        match token.is_garbage() && span.len() > 1 {
+            true => tokens.extend(
+                input[span.clone()]
+                    .char_indices()
+                    .map(|(offset, character)| {
+                        let start = span.start + offset;
+                        (
+                            Token::garbage(character),
+                            start..start + character.len_utf8(),
+                        )
+                    }),
+            ),
+            false => tokens.push((token, span)),
+        }
+    }
+    Ok(tokens)
+}
@@ -0,0 +1,112 @@
+pub mod generate;
+pub mod lexer;
+mod parser;
+pub mod parsers;
+pub mod reference;
+
+use crate::{
+    lexer::Token,
+    parser::{ParserState, ParserStatus},
+};
+use anyhow::anyhow;
+use chumsky::extra::SimpleState;
+use chumsky::Parser;
+use common::{DayInputs, Solution};
+
+pub struct Day3;
+
+pub fn inputs() -> DayInputs {
+    common::day_inputs!()
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Parsed = Vec<Token>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        parsers::parse_with(parsers::BACKENDS[0], input)
+    }
+
+    fn part_1(tokens: &Self::Parsed) -> anyhow::Result<u64> {
+        Ok(tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::Mul((left, right)) => Some(u64::from(left * right)),
+                _ => None,
+            })
+            .sum())
+    }
+
+    fn part_2(tokens: &Self::Parsed) -> anyhow::Result<u64> {
+        let tokens = tokens
+            .iter()
+            .filter(|token| !token.is_garbage())
+            .cloned()
+            .collect::<Vec<_>>();
+        let state = ParserState {
+            status: ParserStatus::Enabled,
+        };
+        let (values, _) = tracing::info_span!("parse").in_scope(|| {
+            parser::parser()
+                .parse_with_state(tokens.as_slice(), &mut SimpleState(state))
+                .into_output_errors()
+        });
+        let values = values.ok_or(anyhow!("Parser didn't return any values"))?;
+
+        Ok(values.into_iter().map(u64::from).sum())
+    }
+
+    /// Garbage tokens are single characters, so the rest of the input is made of instructions.
+    /// Real inputs are a bit under half instructions.
+    fn plausibility(input: &str, tokens: &Self::Parsed) -> f64 {
+        if !tokens.iter().any(|token| matches!(token, Token::Mul(_))) {
+            return 0.0;
+        }
+        let garbage = tokens.iter().filter(|token| token.is_garbage()).count();
+        let instructions = input.len() - garbage;
+        (2.0 * instructions as f64 / input.len() as f64).min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lexer::lex, Day3};
+    use common::{assert_snapshot, get_input, solve_part_1, solve_part_2, InputType, Solution};
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let res = solve_part_1::<Day3>(InputType::Test)?;
+        println!("{}", res);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let res = solve_part_2::<Day3>(InputType::Test)?;
+        println!("{}", res);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_consecutive() -> anyhow::Result<()> {
+        let tokens = Day3::parse("do()don't()mul(2,3)don't()do()mul(4,5)do()")?;
+        assert_eq!(Day3::part_2(&tokens)?, 20);
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() -> anyhow::Result<()> {
+        let tokens = lex(&get_input(InputType::Test)?)?;
+        let snapshot = tokens
+            .iter()
+            .map(|(token, span)| match token.is_garbage() {
+                // Same snapshot with the debug feature, which keeps the garbage character
+                true => format!("{span:?} Garbage\n"),
+                false => format!("{span:?} {token:?}\n"),
+            })
+            .collect::<String>();
+        assert_snapshot!("example", snapshot)
+    }
+}
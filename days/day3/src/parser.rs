@@ -3,10 +3,10 @@ use chumsky::extra::SimpleState;
 use chumsky::{
     extra::State,
     input::MapExtra,
-    prelude::{any, choice, group, just},
+    prelude::{any, choice, just},
     IterParser, Parser,
 };
-use std::ops::{Deref, DerefMut};
+use std::ops::DerefMut;
 
 pub enum ParserStatus {
     Enabled,
@@ -32,24 +32,18 @@ pub fn parser<'a>() -> impl Parser<'a, &'a [Token], Vec<u32>, State<SimpleState<
             }
             _ => unreachable!(),
         });
-    let dos = group((
-        just(Token::Do).map_with(
-            |_, e: &mut MapExtra<&'a [Token], State<SimpleState<ParserState>>>| {
-                e.state().status = ParserStatus::Enabled;
-            },
-        ),
-        muls,
-    ));
-    let donts = group((
-        just(Token::Dont).map_with(
-            |_, e: &mut MapExtra<&'a [Token], State<SimpleState<ParserState>>>| {
-                e.state().status = ParserStatus::Disabled;
-            },
-        ),
-        muls,
-    ));
+    let dos = just(Token::Do).map_with(
+        |_, e: &mut MapExtra<&'a [Token], State<SimpleState<ParserState>>>| {
+            e.state().status = ParserStatus::Enabled;
+            0
+        },
+    );
+    let donts = just(Token::Dont).map_with(
+        |_, e: &mut MapExtra<&'a [Token], State<SimpleState<ParserState>>>| {
+            e.state().status = ParserStatus::Disabled;
+            0
+        },
+    );
 
-    choice((dos.map(|(_, mul)| mul), donts.map(|(_, _)| 0), muls))
-        .repeated()
-        .collect::<Vec<_>>()
+    choice((dos, donts, muls)).repeated().collect::<Vec<_>>()
 }
@@ -0,0 +1,37 @@
+use crate::lexer::Token;
+use anyhow::anyhow;
+use chumsky::{error::Rich, extra, prelude::*};
+
+fn parser<'a>() -> impl Parser<'a, &'a str, Vec<Token>, extra::Err<Rich<'a, char>>> {
+    let number = any()
+        .filter(char::is_ascii_digit)
+        .repeated()
+        .at_least(1)
+        .at_most(3)
+        .to_slice()
+        .try_map(|digits: &str, span| digits.parse::<u32>().map_err(|err| Rich::custom(span, err)));
+    let mul = just("mul(")
+        .ignore_then(number)
+        .then_ignore(just(','))
+        .then(number)
+        .then_ignore(just(')'))
+        .map(Token::Mul);
+    let garbage = any().filter(char::is_ascii).map(Token::garbage);
+
+    choice((
+        mul,
+        just("do()").to(Token::Do),
+        just("don't()").to(Token::Dont),
+        garbage,
+    ))
+    .repeated()
+    .collect()
+    .then_ignore(end())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Token>> {
+    parser()
+        .parse(input)
+        .into_result()
+        .map_err(|errors| anyhow!("Parsing errors: {errors:?}"))
+}
@@ -0,0 +1,6 @@
+use crate::lexer::{lex, Token};
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Token>> {
+    let _span = tracing::info_span!("lex").entered();
+    Ok(lex(input)?.into_iter().map(|(token, _)| token).collect())
+}
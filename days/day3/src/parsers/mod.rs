@@ -0,0 +1,71 @@
+//! The corrupted memory lexer written with each [`Backend`].
+
+mod chumsky;
+mod logos;
+#[cfg(feature = "nom")]
+mod nom;
+#[cfg(feature = "scanner")]
+mod scanner;
+
+use crate::lexer::Token;
+use anyhow::bail;
+use common::parser::Backend;
+
+/// Compiled backends, [`crate::Day3`] parses with the first one. The chumsky and logos ones are
+/// always built, part 2 runs a chumsky parser over the tokens of the logos lexer.
+pub const BACKENDS: &[Backend] = &[
+    #[cfg(feature = "nom")]
+    Backend::Nom,
+    Backend::Chumsky,
+    Backend::Logos,
+    #[cfg(feature = "scanner")]
+    Backend::Scanner,
+];
+
+pub fn parse_with(backend: Backend, input: &str) -> anyhow::Result<Vec<Token>> {
+    match backend {
+        #[cfg(feature = "nom")]
+        Backend::Nom => nom::parse(input),
+        Backend::Chumsky => chumsky::parse(input),
+        Backend::Logos => logos::parse(input),
+        #[cfg(feature = "scanner")]
+        Backend::Scanner => scanner::parse(input),
+        #[allow(unreachable_patterns)]
+        backend => bail!("Built without the {backend} parser"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate::generate,
+        parsers::{parse_with, BACKENDS},
+    };
+    use common::{get_input, InputType};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example() -> anyhow::Result<()> {
+        let input = get_input(InputType::Test)?;
+        let expected = parse_with(BACKENDS[0], &input)?;
+        for &backend in BACKENDS {
+            assert_eq!(parse_with(backend, &input)?, expected, "{backend}");
+            assert!(parse_with(backend, "é").is_err(), "{backend}");
+            assert!(parse_with(backend, "mul(2,3)é").is_err(), "{backend}");
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_backends(seed in any::<u64>(), size in 1..300usize) {
+            let input = generate(seed, size);
+            let expected = parse_with(BACKENDS[0], &input).unwrap();
+            for &backend in BACKENDS {
+                prop_assert_eq!(&parse_with(backend, &input).unwrap(), &expected, "{}", backend);
+            }
+        }
+    }
+}
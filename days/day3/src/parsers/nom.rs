@@ -0,0 +1,47 @@
+use crate::lexer::Token;
+use anyhow::bail;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, satisfy},
+    combinator::{map, map_res, value},
+    multi::many0,
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+fn number(input: &str) -> IResult<&str, u32> {
+    map_res(
+        take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
+        str::parse,
+    )(input)
+}
+
+fn mul(input: &str) -> IResult<&str, Token> {
+    map(
+        delimited(
+            tag("mul("),
+            separated_pair(number, char(','), number),
+            char(')'),
+        ),
+        Token::Mul,
+    )(input)
+}
+
+/// Instructions, or a single ASCII character of garbage.
+fn token(input: &str) -> IResult<&str, Token> {
+    alt((
+        mul,
+        value(Token::Do, tag("do()")),
+        value(Token::Dont, tag("don't()")),
+        map(satisfy(|c| c.is_ascii()), Token::garbage),
+    ))(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Token>> {
+    let (rest, tokens) = many0(token)(input).map_err(|err| err.to_owned())?;
+    if !rest.is_empty() {
+        bail!("Unexpected input at {}", input.len() - rest.len());
+    }
+    Ok(tokens)
+}
@@ -0,0 +1,52 @@
+use crate::lexer::Token;
+use anyhow::bail;
+
+/// One to three digits, as many as there are.
+fn number(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let len = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if !(1..=3).contains(&len) {
+        return None;
+    }
+    let number = bytes[..len]
+        .iter()
+        .fold(0, |number, digit| number * 10 + u32::from(digit - b'0'));
+    Some((number, &bytes[len..]))
+}
+
+/// `mul(a,b)` at the start of `bytes`, with the number of bytes it takes.
+fn mul(bytes: &[u8]) -> Option<((u32, u32), usize)> {
+    let rest = bytes.strip_prefix(b"mul(")?;
+    let (left, rest) = number(rest)?;
+    let rest = rest.strip_prefix(b",")?;
+    let (right, rest) = number(rest)?;
+    let rest = rest.strip_prefix(b")")?;
+    Some(((left, right), bytes.len() - rest.len()))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Token>> {
+    let bytes = input.as_bytes();
+    let mut tokens = vec![];
+    let mut position = 0;
+    while position < bytes.len() {
+        let rest = &bytes[position..];
+        if let Some((pair, len)) = mul(rest) {
+            tokens.push(Token::Mul(pair));
+            position += len;
+        } else if rest.starts_with(b"do()") {
+            tokens.push(Token::Do);
+            position += b"do()".len();
+        } else if rest.starts_with(b"don't()") {
+            tokens.push(Token::Dont);
+            position += b"don't()".len();
+        } else if rest[0].is_ascii() {
+            tokens.push(Token::garbage(char::from(rest[0])));
+            position += 1;
+        } else {
+            bail!("Unexpected input at {position}");
+        }
+    }
+    Ok(tokens)
+}
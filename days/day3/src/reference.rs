@@ -0,0 +1,53 @@
+//! Deliberately simple solutions to check the real ones against, reading the raw input.
+
+/// Reads `mul(a,b)` at the start of `memory`, with one to three digit numbers.
+fn mul(memory: &str) -> Option<u64> {
+    let arguments = memory.strip_prefix("mul(")?;
+    let (arguments, _) = arguments.split_once(')')?;
+    let (left, right) = arguments.split_once(',')?;
+    let is_number = |text: &str| {
+        (1..=3).contains(&text.len()) && text.chars().all(|char| char.is_ascii_digit())
+    };
+    match is_number(left) && is_number(right) {
+        true => Some(left.parse::<u64>().ok()? * right.parse::<u64>().ok()?),
+        false => None,
+    }
+}
+
+fn solve(input: &str, conditionals: bool) -> u64 {
+    let mut enabled = true;
+    let mut total = 0;
+    for start in 0..input.len() {
+        let memory = &input[start..];
+        if memory.starts_with("do()") {
+            enabled = true;
+        } else if memory.starts_with("don't()") {
+            enabled = !conditionals;
+        } else if let Some(product) = mul(memory) {
+            if enabled {
+                total += product;
+            }
+        }
+    }
+    total
+}
+
+pub fn part_1(input: &str) -> anyhow::Result<u64> {
+    Ok(solve(input, false))
+}
+
+pub fn part_2(input: &str) -> anyhow::Result<u64> {
+    Ok(solve(input, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, reference, Day3};
+
+    common::reference_test!(
+        Day3,
+        generate,
+        1..300usize,
+        [reference::part_1, reference::part_2]
+    );
+}
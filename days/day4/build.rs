@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    common::embed::embed_inputs()
+}
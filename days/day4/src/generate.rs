@@ -0,0 +1,64 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const LETTERS: [char; 4] = ['X', 'M', 'A', 'S'];
+
+/// A `size` by `size` word search of X, M, A and S, with `size` extra XMAS written in random
+/// directions on top of the noise.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+    let mut grid = (0..size * size)
+        .map(|_| LETTERS[rng.gen_range(0..LETTERS.len())])
+        .collect::<Vec<_>>();
+
+    if size >= LETTERS.len() {
+        let last = (size - LETTERS.len()) as isize;
+        for _ in 0..size {
+            let (dx, dy) = loop {
+                let offset = (rng.gen_range(-1..=1), rng.gen_range(-1..=1));
+                if offset != (0, 0) {
+                    break offset;
+                }
+            };
+            // Start far enough from the edges the word runs towards
+            let x = rng.gen_range(0..=last) + if dx < 0 { 3 } else { 0 };
+            let y = rng.gen_range(0..=last) + if dy < 0 { 3 } else { 0 };
+            for (step, letter) in LETTERS.iter().enumerate() {
+                let step = step as isize;
+                let position = (y + dy * step) as usize * size + (x + dx * step) as usize;
+                grid[position] = *letter;
+            }
+        }
+    }
+
+    let mut output = String::with_capacity(size * (size + 1));
+    for row in grid.chunks(size) {
+        output.extend(row);
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, Day4};
+    use common::Solution;
+
+    #[test]
+    fn test_generate() -> anyhow::Result<()> {
+        assert_eq!(generate(7, 30), generate(7, 30));
+        assert_ne!(generate(7, 30), generate(8, 30));
+        let grid = generate(7, 30);
+        assert_eq!(grid.lines().count(), 30);
+        assert!(grid.lines().all(|line| line.len() == 30));
+        assert!(grid
+            .lines()
+            .flat_map(str::chars)
+            .all(|c| "XMAS".contains(c)));
+        assert!(generate(7, 2).lines().all(|line| line.len() == 2));
+        let parsed = Day4::parse(&grid)?;
+        assert_eq!((parsed.width(), parsed.height()), (30, 30));
+        Ok(())
+    }
+}
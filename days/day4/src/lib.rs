@@ -0,0 +1,172 @@
+pub mod generate;
+pub mod parsers;
+pub mod reference;
+
+use common::{grid::Grid, DayInputs, GridView, Solution};
+use std::iter;
+
+/// Letters of an X-MAS as offsets from its `A`, with both `M`s on top.
+const X_MAS: [((isize, isize), char); 5] = [
+    ((0, 0), 'A'),
+    ((-1, -1), 'M'),
+    ((1, -1), 'M'),
+    ((-1, 1), 'S'),
+    ((1, 1), 'S'),
+];
+
+/// Positions of the letters of every XMAS. The rows of the four quarter turns of the grid read it
+/// in the four straight directions and their diagonals in the four diagonal ones.
+pub fn find_xmas(grid: &Grid<char>) -> Vec<[(usize, usize); 4]> {
+    let mut found = vec![];
+    for view in grid.enumerate().rotations() {
+        let rows = view.rows().map(<[_]>::to_vec);
+        let diagonals = view
+            .diagonals()
+            .map(|diagonal| diagonal.copied().collect::<Vec<_>>());
+        for line in rows.chain(diagonals) {
+            for word in line.windows(4) {
+                if word.iter().map(|&(_, &char)| char).eq("XMAS".chars()) {
+                    found.push([word[0].0, word[1].0, word[2].0, word[3].0]);
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Positions of the letters of every X-MAS, in the order `A`, `M`, `M`, `S`, `S`. Each cell is the
+/// centre of one if a quarter turn of the offsets of [`X_MAS`] around it reads the letters.
+pub fn find_x_mas(grid: &Grid<char>) -> Vec<[(usize, usize); 5]> {
+    let turns = iter::successors(Some(X_MAS), |letters| {
+        Some(letters.map(|((dx, dy), letter)| ((-dy, dx), letter)))
+    });
+    let turns: Vec<_> = turns.take(4).collect();
+    grid.iter()
+        .filter_map(|((x, y), _)| {
+            let (x, y) = (x as isize, y as isize);
+            let letters = turns.iter().find(|letters| {
+                letters
+                    .iter()
+                    .all(|&((dx, dy), letter)| grid.get(x + dx, y + dy) == Some(&letter))
+            })?;
+            Some(letters.map(|((dx, dy), _)| ((x + dx) as usize, (y + dy) as usize)))
+        })
+        .collect()
+}
+
+pub struct Day4;
+
+pub fn inputs() -> DayInputs {
+    common::day_inputs!()
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Parsed = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Grid::from_rows(parsers::parse_with(parsers::BACKENDS[0], input)?)
+    }
+
+    fn part_1(grid: &Self::Parsed) -> anyhow::Result<usize> {
+        Ok(find_xmas(grid).len())
+    }
+
+    fn part_2(grid: &Self::Parsed) -> anyhow::Result<usize> {
+        Ok(find_x_mas(grid).len())
+    }
+
+    /// Share of the cells holding a letter of XMAS, the only ones word searches use. Parsing
+    /// already rejects rows of different widths.
+    fn plausibility(_input: &str, chars: &Self::Parsed) -> f64 {
+        let cells = chars.cells();
+        let letters = cells.iter().filter(|&&char| "XMAS".contains(char)).count();
+        letters as f64 / cells.len().max(1) as f64
+    }
+
+    fn grid_view(grid: &Self::Parsed) -> Option<GridView> {
+        let rows = grid.rows().map(|row| row.iter().collect()).collect();
+        let part_1 = find_xmas(grid).concat();
+        let part_2 = find_x_mas(grid).concat();
+
+        Some(GridView {
+            rows,
+            part_1,
+            part_2,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_x_mas, Day4};
+    use common::{assert_snapshot, get_input, solve_part_1, solve_part_2, InputType, Solution};
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let count = solve_part_1::<Day4>(InputType::Test)?;
+
+        println!("{count}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let count = solve_part_2::<Day4>(InputType::Test)?;
+
+        println!("{count}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_view() -> anyhow::Result<()> {
+        let parsed = Day4::parse(&get_input(InputType::Test)?)?;
+        let view = Day4::grid_view(&parsed).ok_or(anyhow::anyhow!("No grid view"))?;
+
+        assert_eq!(view.rows.len(), 10);
+        assert_eq!(view.part_1.len(), 18 * 4);
+        assert_eq!(view.part_2.len(), 9 * 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_x_mas_order() -> anyhow::Result<()> {
+        let grid = Day4::parse(&get_input(InputType::Test)?)?;
+        let found = find_x_mas(&grid);
+
+        assert_eq!(found.len(), 9);
+        for positions in found {
+            let letters: String = positions.iter().map(|&position| grid[position]).collect();
+            assert_eq!(letters, "AMMSS");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(Day4::parse("").is_err());
+        assert!(Day4::parse("\nXMAS\n").is_err());
+        assert!(Day4::parse("XMASXMASXM\nXMAS\n").is_err());
+    }
+
+    #[test]
+    fn test_widths() -> anyhow::Result<()> {
+        let narrow = Day4::parse("XMAS\nMMAS\nAAAS\nSSSS\n")?;
+        assert_eq!(Day4::part_1(&narrow)?, 3);
+        let example = Day4::parse(&get_input(InputType::Test)?)?;
+        assert_eq!(Day4::part_1(&example)?, 18);
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() -> anyhow::Result<()> {
+        let grid = Day4::parse(&get_input(InputType::Test)?)?;
+        assert_snapshot!("example", grid.to_string())
+    }
+}
@@ -0,0 +1,24 @@
+use anyhow::anyhow;
+use chumsky::{error::Rich, extra, prelude::*};
+
+fn parser<'a>() -> impl Parser<'a, &'a str, Vec<Vec<char>>, extra::Err<Rich<'a, char>>> {
+    let cell = none_of('\n');
+    let row = cell.repeated().collect::<Vec<_>>().then_ignore(just('\n'));
+    let last_row = cell.repeated().at_least(1).collect::<Vec<_>>().or_not();
+
+    row.repeated()
+        .collect::<Vec<_>>()
+        .then(last_row)
+        .then_ignore(end())
+        .map(|(mut rows, last)| {
+            rows.extend(last);
+            rows
+        })
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<char>>> {
+    parser()
+        .parse(input)
+        .into_result()
+        .map_err(|errors| anyhow!("Parsing errors: {errors:?}"))
+}
@@ -0,0 +1,34 @@
+use anyhow::anyhow;
+use logos::Logos;
+use std::mem;
+
+#[derive(Debug, Logos, PartialEq)]
+enum Token {
+    #[regex("[^\n]", |lex| lex.slice().chars().next())]
+    Cell(char),
+    #[token("\n")]
+    NewLine,
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<char>>> {
+    let tokens = tracing::info_span!("lex").in_scope(|| {
+        Token::lexer(input)
+            .spanned()
+            .map(|(token, span)| token.map_err(|_| anyhow!("Unexpected input at {span:?}")))
+            .collect::<anyhow::Result<Vec<_>>>()
+    })?;
+
+    let _span = tracing::info_span!("parse").entered();
+    let mut rows = vec![];
+    let mut row = vec![];
+    for token in tokens {
+        match token {
+            Token::Cell(cell) => row.push(cell),
+            Token::NewLine => rows.push(mem::take(&mut row)),
+        }
+    }
+    if !row.is_empty() {
+        rows.push(row);
+    }
+    Ok(rows)
+}
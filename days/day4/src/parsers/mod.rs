@@ -0,0 +1,85 @@
+//! The grid parser written with each [`Backend`], giving the rows of the grid.
+
+#[cfg(feature = "chumsky")]
+mod chumsky;
+#[cfg(feature = "logos")]
+mod logos;
+#[cfg(feature = "nom")]
+mod nom;
+#[cfg(feature = "scanner")]
+mod scanner;
+
+use anyhow::bail;
+use common::parser::Backend;
+
+#[cfg(not(any(
+    feature = "nom",
+    feature = "chumsky",
+    feature = "logos",
+    feature = "scanner"
+)))]
+compile_error!("Enable at least one of the nom, chumsky, logos or scanner features");
+
+/// Compiled backends, [`crate::Day4`] parses with the first one.
+pub const BACKENDS: &[Backend] = &[
+    #[cfg(feature = "nom")]
+    Backend::Nom,
+    #[cfg(feature = "chumsky")]
+    Backend::Chumsky,
+    #[cfg(feature = "logos")]
+    Backend::Logos,
+    #[cfg(feature = "scanner")]
+    Backend::Scanner,
+];
+
+pub fn parse_with(backend: Backend, input: &str) -> anyhow::Result<Vec<Vec<char>>> {
+    match backend {
+        #[cfg(feature = "scanner")]
+        Backend::Scanner => scanner::parse(input),
+        #[cfg(feature = "nom")]
+        Backend::Nom => nom::parse(input),
+        #[cfg(feature = "chumsky")]
+        Backend::Chumsky => chumsky::parse(input),
+        #[cfg(feature = "logos")]
+        Backend::Logos => logos::parse(input),
+        #[allow(unreachable_patterns)]
+        backend => bail!("Built without the {backend} parser"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate::generate,
+        parsers::{parse_with, BACKENDS},
+    };
+    use common::{get_input, InputType};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example() -> anyhow::Result<()> {
+        let input = get_input(InputType::Test)?;
+        let expected = parse_with(BACKENDS[0], &input)?;
+        for &backend in BACKENDS {
+            assert_eq!(parse_with(backend, &input)?, expected, "{backend}");
+            for edge in ["", "\n", "XM\n\nAS\n", "XM\nAS"] {
+                let expected = parse_with(BACKENDS[0], edge)?;
+                assert_eq!(parse_with(backend, edge)?, expected, "{backend} {edge:?}");
+            }
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_backends(seed in any::<u64>(), size in 1..300usize) {
+            let input = generate(seed, size);
+            let expected = parse_with(BACKENDS[0], &input).unwrap();
+            for &backend in BACKENDS {
+                prop_assert_eq!(&parse_with(backend, &input).unwrap(), &expected, "{}", backend);
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use nom::{
+    character::complete::{char, none_of},
+    combinator::{all_consuming, opt},
+    multi::{many0, many1},
+    sequence::{pair, terminated},
+    IResult,
+};
+
+fn rows(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    let (input, (mut rows, last)) = all_consuming(pair(
+        many0(terminated(many0(none_of("\n")), char('\n'))),
+        opt(many1(none_of("\n"))),
+    ))(input)?;
+    rows.extend(last);
+    Ok((input, rows))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<char>>> {
+    let (_, rows) = rows(input).map_err(|err| err.to_owned())?;
+    Ok(rows)
+}
@@ -0,0 +1,14 @@
+/// Rows end at a new line, the last one doesn't need to.
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<char>>> {
+    let mut rows = vec![];
+    let mut rest = input;
+    while !rest.is_empty() {
+        let end = rest
+            .bytes()
+            .position(|byte| byte == b'\n')
+            .unwrap_or(rest.len());
+        rows.push(rest[..end].chars().collect());
+        rest = rest.get(end + 1..).unwrap_or_default();
+    }
+    Ok(rows)
+}
@@ -0,0 +1,63 @@
+//! Deliberately simple solutions to check the real ones against, reading the raw input.
+
+fn grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Letter at `(x, y)`, nothing outside the grid.
+fn at(grid: &[Vec<char>], x: isize, y: isize) -> Option<char> {
+    let row = grid.get(usize::try_from(y).ok()?)?;
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+pub fn part_1(input: &str) -> anyhow::Result<u64> {
+    let grid = grid(input);
+    let mut found = 0;
+    for y in 0..grid.len() as isize {
+        for x in 0..grid[y as usize].len() as isize {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let word = (0..4)
+                        .filter_map(|step| at(&grid, x + dx * step, y + dy * step))
+                        .collect::<String>();
+                    if (dx, dy) != (0, 0) && word == "XMAS" {
+                        found += 1;
+                    }
+                }
+            }
+        }
+    }
+    Ok(found)
+}
+
+pub fn part_2(input: &str) -> anyhow::Result<u64> {
+    let grid = grid(input);
+    let mut found = 0;
+    for y in 0..grid.len() as isize {
+        for x in 0..grid[y as usize].len() as isize {
+            let diagonal = |dx: isize| {
+                [-1, 0, 1]
+                    .iter()
+                    .filter_map(|&step| at(&grid, x + dx * step, y + step))
+                    .collect::<String>()
+            };
+            let is_mas = |word: String| word == "MAS" || word == "SAM";
+            if is_mas(diagonal(1)) && is_mas(diagonal(-1)) {
+                found += 1;
+            }
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, reference, Day4};
+
+    common::reference_test!(
+        Day4,
+        generate,
+        1..30_usize,
+        [reference::part_1, reference::part_2]
+    );
+}
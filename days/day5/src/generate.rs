@@ -0,0 +1,101 @@
+use rand::{
+    seq::{index, SliceRandom},
+    Rng, SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+use std::collections::BTreeSet;
+
+/// Longest update, the real input doesn't go past 23 pages.
+const MAX_UPDATE_LEN: usize = 23;
+
+/// Ordering rules and `size` updates. The pages follow one hidden order that every rule agrees
+/// with, but only some inputs have a rule for every pair of pages. The others have the rules
+/// between pages next to each other in an update plus a random share of the rest, so the rules
+/// aren't transitive, and some also miss a few of those links, leaving updates without a single
+/// correct order. Updates have an odd number of pages and about half of them are already
+/// correctly ordered.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    // Two digit page numbers, in their hidden order
+    let mut pages = (10..100).collect::<Vec<usize>>();
+    pages.shuffle(&mut rng);
+    pages.truncate(size.clamp(5, pages.len()));
+
+    // Updates as positions in the hidden order
+    let max_half = (MAX_UPDATE_LEN.min(pages.len()) - 1) / 2;
+    let updates = (0..size)
+        .map(|_| {
+            let len = 2 * rng.gen_range(1..=max_half) + 1;
+            let mut positions = index::sample(&mut rng, pages.len(), len).into_vec();
+            positions.sort_unstable();
+            positions
+        })
+        .collect::<Vec<_>>();
+
+    // Chances of a rule between neighbours in an update and between any other two pages
+    let (link_chance, other_chance) = match rng.gen_range(0..3) {
+        0 => (1.0, 1.0),
+        1 => (1.0, rng.gen_range(0.0..0.5)),
+        _ => (0.9, rng.gen_range(0.0..0.5)),
+    };
+    let mut rules = BTreeSet::new();
+    for update in &updates {
+        for pair in update.windows(2) {
+            if rng.gen_bool(link_chance) {
+                rules.insert((pair[0], pair[1]));
+            }
+        }
+    }
+    for left in 0..pages.len() {
+        for right in left + 1..pages.len() {
+            if rng.gen_bool(other_chance) {
+                rules.insert((left, right));
+            }
+        }
+    }
+    let mut rules = rules.into_iter().collect::<Vec<_>>();
+    rules.shuffle(&mut rng);
+
+    let mut output = String::new();
+    for (left, right) in rules {
+        output.push_str(&format!("{}|{}\n", pages[left], pages[right]));
+    }
+    output.push('\n');
+
+    for mut positions in updates {
+        if rng.gen_bool(0.5) {
+            positions.shuffle(&mut rng);
+        }
+        let update = positions
+            .iter()
+            .map(|&position| pages[position].to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        output.push_str(&update);
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, Day5};
+    use common::Solution;
+
+    #[test]
+    fn test_generate() -> anyhow::Result<()> {
+        assert_eq!(generate(7, 100), generate(7, 100));
+        assert_ne!(generate(7, 100), generate(8, 100));
+        let (updates, rules) = Day5::parse(&generate(7, 100))?;
+        assert_eq!(updates.len(), 100);
+        assert!(updates
+            .iter()
+            .all(|update| update.list.len() % 2 == 1 && update.list.len() <= 23));
+        // Every rule agrees with the hidden order
+        assert!(rules.node_count() <= 90 && rules.toposort().is_ok());
+        Day5::parse(&generate(7, 1))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+pub mod generate;
+pub mod parsers;
+pub mod reference;
+pub mod types;
+
+use crate::types::Update;
+use anyhow::bail;
+use common::{graph::DiGraph, DayInputs, Solution};
+
+/// No page has a rule saying it belongs before an earlier page.
+fn is_ordered(list: &[usize], rules: &DiGraph<usize>) -> bool {
+    list.iter().enumerate().all(|(index, page)| {
+        !list[..index]
+            .iter()
+            .any(|earlier| rules.contains_edge(page, earlier))
+    })
+}
+
+pub struct Day5;
+
+pub fn inputs() -> DayInputs {
+    common::day_inputs!()
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Parsed = (Vec<Update>, DiGraph<usize>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        let (ordering_rules, updates) = parsers::parse_with(parsers::BACKENDS[0], input)?;
+
+        let _span = tracing::info_span!("rule_graph").entered();
+        let rules = ordering_rules
+            .iter()
+            .map(|rule| (rule.left, rule.right))
+            .collect();
+
+        Ok((updates, rules))
+    }
+
+    fn part_1((updates, rules): &Self::Parsed) -> anyhow::Result<usize> {
+        let result = updates
+            .iter()
+            .filter(|update| is_ordered(&update.list, rules))
+            .map(|update| {
+                let middle = update.list.len() / 2;
+                update.list[middle]
+            })
+            .sum::<usize>();
+
+        Ok(result)
+    }
+
+    fn part_2((updates, rules): &Self::Parsed) -> anyhow::Result<usize> {
+        let mut result = 0;
+        for update in updates {
+            if is_ordered(&update.list, rules) {
+                continue;
+            }
+
+            let update_rules = rules.induced_subgraph(&update.list);
+            let order = match update_rules.toposort() {
+                Ok(order) => order,
+                Err(cycle) => bail!(
+                    "The rules don't order update {:?}, {cycle:?} go in a circle",
+                    update.list
+                ),
+            };
+            // The order is the only one when a rule links each page to the next, and pages
+            // appearing twice would be merged into one node
+            let linked = order
+                .windows(2)
+                .all(|pair| update_rules.contains_edge(&pair[0], &pair[1]));
+            if !linked || order.len() != update.list.len() {
+                bail!("The rules don't order update {:?}", update.list);
+            }
+
+            result += order[order.len() / 2];
+        }
+
+        Ok(result)
+    }
+
+    /// The parser is strict already, but accepts inputs without rules or updates.
+    fn plausibility(_input: &str, (updates, rules): &Self::Parsed) -> f64 {
+        match updates.is_empty() || rules.node_count() == 0 {
+            true => 0.5,
+            false => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day5;
+    use common::{assert_snapshot, get_input, solve_part_1, solve_part_2, InputType, Solution};
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let res = solve_part_1::<Day5>(InputType::Test)?;
+        println!("{}", res);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let res = solve_part_2::<Day5>(InputType::Test)?;
+        println!("{}", res);
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() -> anyhow::Result<()> {
+        // Pages without rules, rules in a cycle, an empty update and an overflowing page
+        let parsed = Day5::parse("1|2\n2|3\n3|1\n\n4,5,6\n3,2,1\n")?;
+        assert_eq!(Day5::part_1(&parsed)?, 5);
+        assert!(Day5::part_2(&parsed).is_err());
+        assert!(Day5::parse("1|2\n\n,\n").is_err());
+        assert!(Day5::parse("1|99999999999999999999\n\n1\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() -> anyhow::Result<()> {
+        let (updates, rules) = Day5::parse(&get_input(InputType::Test)?)?;
+        let mut snapshot = String::from("Rules\n");
+        let mut pages = rules.nodes().collect::<Vec<_>>();
+        pages.sort();
+        for page in pages {
+            let lower = rules.predecessors(page).collect::<Vec<_>>();
+            let higher = rules.successors(page).collect::<Vec<_>>();
+            snapshot.push_str(&format!("{page}: after {lower:?}, before {higher:?}\n"));
+        }
+        snapshot.push_str("Updates\n");
+        for update in updates {
+            snapshot.push_str(&format!("{:?}\n", update.list));
+        }
+        assert_snapshot!("example", snapshot)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::types::{PageOrderingRule, Update};
+use anyhow::anyhow;
+use chumsky::{error::Rich, extra, prelude::*, text};
+
+fn parser<'a>(
+) -> impl Parser<'a, &'a str, (Vec<PageOrderingRule>, Vec<Update>), extra::Err<Rich<'a, char>>> {
+    let number = text::digits(10).to_slice().try_map(|digits: &str, span| {
+        digits
+            .parse::<usize>()
+            .map_err(|err| Rich::custom(span, err))
+    });
+    let rule = number
+        .then_ignore(just('|'))
+        .then(number)
+        .then_ignore(just('\n'))
+        .map(|(left, right)| PageOrderingRule { left, right });
+    let update = number
+        .separated_by(just(','))
+        .at_least(1)
+        .collect()
+        .then_ignore(just('\n'))
+        .map(|list| Update { list });
+
+    rule.repeated()
+        .collect()
+        .then_ignore(just('\n'))
+        .then(update.repeated().collect())
+        .then_ignore(end())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<(Vec<PageOrderingRule>, Vec<Update>)> {
+    parser()
+        .parse(input)
+        .into_result()
+        .map_err(|errors| anyhow!("Parsing errors: {errors:?}"))
+}
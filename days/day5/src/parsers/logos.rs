@@ -0,0 +1,69 @@
+use crate::types::{PageOrderingRule, Update};
+use anyhow::{anyhow, bail};
+use chumsky::{
+    error::Rich,
+    extra::Full,
+    prelude::{any, group, just},
+    IterParser, Parser,
+};
+use logos::Logos;
+
+#[derive(Clone, Debug, Logos, PartialEq)]
+enum Token {
+    #[regex("[0-9]+", |lex| lex.slice().parse::<usize>().ok())]
+    Numeric(usize),
+    #[token("|")]
+    Separator,
+    #[token("\n")]
+    NewLine,
+    #[token(",")]
+    Comma,
+}
+
+fn parser<'a>(
+) -> impl Parser<'a, &'a [Token], (Vec<PageOrderingRule>, Vec<Update>), Full<Rich<'a, Token>, (), ()>>
+{
+    let num = any()
+        .filter(|token| matches!(token, Token::Numeric(_)))
+        .map(|token: Token| match token {
+            Token::Numeric(num) => num,
+            _ => unreachable!(),
+        });
+
+    let page_ordering = group((num, just(Token::Separator), num, just(Token::NewLine)))
+        .map(|(left, _, right, _)| PageOrderingRule { left, right });
+
+    let update = group((
+        num.separated_by(just(Token::Comma))
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .map(|list| Update { list }),
+        just(Token::NewLine),
+    ))
+    .map(|(update, _)| update);
+
+    group((
+        page_ordering.repeated().collect::<Vec<_>>(),
+        just(Token::NewLine),
+        update.repeated().collect::<Vec<_>>(),
+    ))
+    .map(|(line1, _, line2)| (line1, line2))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<(Vec<PageOrderingRule>, Vec<Update>)> {
+    let tokens = tracing::info_span!("lex").in_scope(|| {
+        let lexer = Token::lexer(input);
+        lexer
+            .spanned()
+            .map(|(lex, span)| lex.map_err(|_| anyhow!("Unexpected input at {span:?}")))
+            .collect::<anyhow::Result<Vec<_>>>()
+    })?;
+    let parsed = tracing::info_span!("parse").in_scope(|| parser().parse(tokens.as_slice()));
+    let (output, errors) = parsed.into_output_errors();
+
+    if !errors.is_empty() {
+        bail!("Parsing errors: {:?}", errors);
+    }
+
+    output.ok_or(anyhow!("No output"))
+}
@@ -0,0 +1,94 @@
+//! The rules and updates parser written with each [`Backend`].
+
+#[cfg(feature = "chumsky")]
+mod chumsky;
+#[cfg(feature = "logos")]
+mod logos;
+#[cfg(feature = "nom")]
+mod nom;
+#[cfg(feature = "scanner")]
+mod scanner;
+
+use crate::types::{PageOrderingRule, Update};
+use anyhow::bail;
+use common::parser::Backend;
+
+#[cfg(not(any(
+    feature = "nom",
+    feature = "chumsky",
+    feature = "logos",
+    feature = "scanner"
+)))]
+compile_error!("Enable at least one of the nom, chumsky, logos or scanner features");
+
+/// Compiled backends, [`crate::Day5`] parses with the first one.
+pub const BACKENDS: &[Backend] = &[
+    #[cfg(feature = "nom")]
+    Backend::Nom,
+    #[cfg(feature = "chumsky")]
+    Backend::Chumsky,
+    #[cfg(feature = "logos")]
+    Backend::Logos,
+    #[cfg(feature = "scanner")]
+    Backend::Scanner,
+];
+
+pub fn parse_with(
+    backend: Backend,
+    input: &str,
+) -> anyhow::Result<(Vec<PageOrderingRule>, Vec<Update>)> {
+    match backend {
+        #[cfg(feature = "logos")]
+        Backend::Logos => logos::parse(input),
+        #[cfg(feature = "nom")]
+        Backend::Nom => nom::parse(input),
+        #[cfg(feature = "chumsky")]
+        Backend::Chumsky => chumsky::parse(input),
+        #[cfg(feature = "scanner")]
+        Backend::Scanner => scanner::parse(input),
+        #[allow(unreachable_patterns)]
+        backend => bail!("Built without the {backend} parser"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate::generate,
+        parsers::{parse_with, BACKENDS},
+    };
+    use common::{get_input, InputType};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example() -> anyhow::Result<()> {
+        let input = get_input(InputType::Test)?;
+        let expected = parse_with(BACKENDS[0], &input)?;
+        for &backend in BACKENDS {
+            assert_eq!(parse_with(backend, &input)?, expected, "{backend}");
+            assert!(parse_with(backend, "").is_err(), "{backend}");
+            assert!(parse_with(backend, "1|2\n").is_err(), "{backend}");
+            assert!(parse_with(backend, "1|2\n\n,\n").is_err(), "{backend}");
+            assert!(parse_with(backend, "1|2\n\n1,\n").is_err(), "{backend}");
+            assert!(parse_with(backend, "1|2\n\n1").is_err(), "{backend}");
+            assert!(
+                parse_with(backend, "1|99999999999999999999\n\n1\n").is_err(),
+                "{backend}"
+            );
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_backends(seed in any::<u64>(), size in 1..300usize) {
+            let input = generate(seed, size);
+            let expected = parse_with(BACKENDS[0], &input).unwrap();
+            for &backend in BACKENDS {
+                prop_assert_eq!(&parse_with(backend, &input).unwrap(), &expected, "{}", backend);
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::types::{PageOrderingRule, Update};
+use nom::{
+    character::complete::{char, digit1},
+    combinator::{all_consuming, map, map_res},
+    multi::{many0, separated_list1},
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+fn number(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn rule(input: &str) -> IResult<&str, PageOrderingRule> {
+    map(
+        terminated(separated_pair(number, char('|'), number), char('\n')),
+        |(left, right)| PageOrderingRule { left, right },
+    )(input)
+}
+
+fn update(input: &str) -> IResult<&str, Update> {
+    map(
+        terminated(separated_list1(char(','), number), char('\n')),
+        |list| Update { list },
+    )(input)
+}
+
+fn rules_and_updates(input: &str) -> IResult<&str, (Vec<PageOrderingRule>, Vec<Update>)> {
+    all_consuming(separated_pair(many0(rule), char('\n'), many0(update)))(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<(Vec<PageOrderingRule>, Vec<Update>)> {
+    let (_, parsed) = rules_and_updates(input).map_err(|err| err.to_owned())?;
+    Ok(parsed)
+}
@@ -0,0 +1,43 @@
+use crate::types::{PageOrderingRule, Update};
+use anyhow::bail;
+use common::parser::scan_number;
+
+fn expect(bytes: &[u8], expected: u8) -> anyhow::Result<&[u8]> {
+    match bytes.split_first() {
+        Some((&byte, rest)) if byte == expected => Ok(rest),
+        _ => bail!("Expected {:?}", char::from(expected)),
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<(Vec<PageOrderingRule>, Vec<Update>)> {
+    let mut bytes = input.as_bytes();
+
+    let mut rules = vec![];
+    while !bytes.starts_with(b"\n") {
+        let (left, rest) = scan_number(bytes)?;
+        let (right, rest) = scan_number(expect(rest, b'|')?)?;
+        bytes = expect(rest, b'\n')?;
+        rules.push(PageOrderingRule { left, right });
+    }
+    bytes = expect(bytes, b'\n')?;
+
+    let mut updates = vec![];
+    while !bytes.is_empty() {
+        let mut list = vec![];
+        loop {
+            let (page, rest) = scan_number(bytes)?;
+            list.push(page);
+            match rest.split_first() {
+                Some((b',', rest)) => bytes = rest,
+                Some((b'\n', rest)) => {
+                    bytes = rest;
+                    break;
+                }
+                _ => bail!("Expected ',' or a new line after page {page}"),
+            }
+        }
+        updates.push(Update { list });
+    }
+
+    Ok((rules, updates))
+}
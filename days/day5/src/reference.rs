@@ -0,0 +1,91 @@
+//! Deliberately simple solutions to check the real ones against, reading the raw input.
+
+use anyhow::bail;
+use std::collections::HashSet;
+
+type Rules = HashSet<(u64, u64)>;
+
+fn read(input: &str) -> anyhow::Result<(Rules, Vec<Vec<u64>>)> {
+    let mut rules = Rules::new();
+    let mut updates = vec![];
+    for line in input.lines() {
+        if let Some((before, after)) = line.split_once('|') {
+            rules.insert((before.parse()?, after.parse()?));
+        } else if !line.is_empty() {
+            let pages = line.split(',').map(str::parse).collect::<Result<_, _>>()?;
+            updates.push(pages);
+        }
+    }
+    Ok((rules, updates))
+}
+
+/// No page has a rule saying it belongs before an earlier page.
+fn is_ordered(rules: &Rules, update: &[u64]) -> bool {
+    for first in 0..update.len() {
+        for second in first + 1..update.len() {
+            if rules.contains(&(update[second], update[first])) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// The update in the only order the rules allow, counting for each page the pages left to place
+/// that have to come before it. Nothing when several pages could go next.
+fn sorted(rules: &Rules, update: &[u64]) -> Option<Vec<u64>> {
+    let mut remaining = update.to_vec();
+    let mut sorted = vec![];
+    while !remaining.is_empty() {
+        let free = remaining
+            .iter()
+            .filter(|&&page| {
+                let before = remaining
+                    .iter()
+                    .filter(|&&other| rules.contains(&(other, page)))
+                    .count();
+                before == 0
+            })
+            .collect::<Vec<_>>();
+        let [&next] = free.as_slice() else {
+            return None;
+        };
+        sorted.push(next);
+        remaining.retain(|&page| page != next);
+    }
+    // Pages appearing twice are placed once
+    (sorted.len() == update.len()).then_some(sorted)
+}
+
+pub fn part_1(input: &str) -> anyhow::Result<u64> {
+    let (rules, updates) = read(input)?;
+    Ok(updates
+        .iter()
+        .filter(|update| is_ordered(&rules, update))
+        .map(|update| update[update.len() / 2])
+        .sum())
+}
+
+pub fn part_2(input: &str) -> anyhow::Result<u64> {
+    let (rules, updates) = read(input)?;
+    let mut sum = 0;
+    for update in updates.iter().filter(|update| !is_ordered(&rules, update)) {
+        let Some(sorted) = sorted(&rules, update) else {
+            bail!("The rules don't order update {update:?}");
+        };
+        sum += sorted[sorted.len() / 2];
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::generate, reference, Day5};
+
+    common::reference_test!(
+        Day5,
+        generate,
+        1..300usize,
+        [reference::part_1, reference::part_2]
+    );
+}
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Errors are fine, panics aren't. The grid view walks the grid on its own, so it's fuzzed too.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        for &backend in day4::parsers::BACKENDS {
+            let _ = day4::parsers::parse_with(backend, input);
+        }
+        let _ = common::execute::<day4::Day4>(input);
+        let _ = common::grid_view::<day4::Day4>(input);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Errors are fine, panics aren't
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        for &backend in day5::parsers::BACKENDS {
+            let _ = day5::parsers::parse_with(backend, input);
+        }
+        let _ = common::execute::<day5::Day5>(input);
+    }
+});